@@ -4,7 +4,7 @@ use musicbrainz_rs::prelude::*;
 fn main() {
     let nirvana = Artist::fetch()
         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
-        .execute();
+        .execute_blocking();
 
     assert_eq!(nirvana.unwrap().name, "Nirvana".to_string());
 }
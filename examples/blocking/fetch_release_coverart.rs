@@ -7,7 +7,7 @@ fn main() {
     // CoverArt Query for a Release.
     let in_utero_coverart = Release::fetch_coverart()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get cover art");
 
     if let CoverartResponse::Json(coverart) = in_utero_coverart {
@@ -22,13 +22,13 @@ fn main() {
 
     let in_utero = Release::fetch()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get release");
 
     // Calling `get_coverart()` method on an already fetched Release entity.
     let in_utero_coverart = in_utero
         .get_coverart()
-        .execute()
+        .execute_blocking()
         .expect("Unable to get coverart");
 
     if let CoverartResponse::Json(coverart) = in_utero_coverart {
@@ -46,7 +46,7 @@ fn main() {
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
         .res_500()
         .back()
-        .execute()
+        .execute_blocking()
         .expect("Unable to get cover art");
 
     if let CoverartResponse::Url(coverart_url) = in_utero_500px_front_coverart {
@@ -6,7 +6,7 @@ fn main() {
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_tags()
         .with_ratings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ninja_tune
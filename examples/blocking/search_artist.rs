@@ -8,7 +8,7 @@ fn main() {
         .country("US")
         .build();
 
-    let query_result = Artist::search(query).execute().unwrap();
+    let query_result = Artist::search(query).execute_blocking().unwrap();
     let query_result: Vec<String> = query_result
         .entities
         .iter()
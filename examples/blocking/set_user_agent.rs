@@ -10,7 +10,7 @@ fn main() {
 
     let nirvana = Artist::fetch()
         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
-        .execute_with_client(&client);
+        .execute_with_client_blocking(&client);
 
     assert_eq!(nirvana.unwrap().name, "Nirvana".to_string());
 }
@@ -0,0 +1,262 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::transport::{HttpRequest, HttpResponse};
+
+/// A cache of the raw JSON responses of musicbrainz.org and coverartarchive.org, stored on disk.
+///
+/// Each response is saved with its `ETag` and `Last-Modified` headers. Once it is older than the max age,
+/// the client revalidates it with `If-None-Match` and `If-Modified-Since`, and a `304 Not Modified` answer
+/// counts as a hit.
+///
+/// ## Example
+/// ```rust
+/// # use core::time::Duration;
+/// # use musicbrainz_rs::cache::DiskCache;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # fn main() -> Result<(), musicbrainz_rs::Error> {
+/// let cache = DiskCache::new(std::env::temp_dir().join("musicbrainz_rs"))
+///     .with_max_age(Duration::from_secs(7 * 24 * 60 * 60));
+///
+/// let client = MusicBrainzClient::builder().disk_cache(cache).build()?;
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DiskCache {
+    /// The directory holding the cached responses
+    dir: PathBuf,
+
+    /// How long a response is used before being revalidated
+    max_age: Duration,
+
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// The result of a cache lookup
+pub(crate) enum DiskCacheLookup {
+    /// The cached response is fresh and can be used as is
+    Fresh(HttpResponse),
+
+    /// The request must be sent. If a stale response is cached, the request now carries its validators
+    Send(Option<DiskCacheEntry>),
+}
+
+/// A cached response, as written on disk
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DiskCacheEntry {
+    /// The request url
+    url: String,
+
+    /// The url the response comes from, after redirects
+    response_url: String,
+    status: u16,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: DateTime<Utc>,
+    body: String,
+}
+
+impl DiskCache {
+    /// Create a cache in the directory `dir`, revalidating responses older than a day.
+    ///
+    /// The directory is created on the first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_age: Duration::from_secs(24 * 60 * 60),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Set how long a response is used before being revalidated. A zero max age revalidates every response.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// The directory holding the cached responses
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The number of requests answered from the cache, revalidated ones included
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// The number of requests that had to be downloaded
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Remove all the cached responses
+    pub fn clear(&self) -> std::io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let hash = Sha256::digest(url.as_bytes());
+        let name: String = hash.iter().map(|byte| format!("{byte:02x}")).collect();
+
+        self.dir.join(format!("{name}.json"))
+    }
+
+    /// Look for the cached response of a request
+    pub(crate) fn lookup(&self, request: &mut HttpRequest) -> DiskCacheLookup {
+        let Some(entry) = self.read(&request.url) else {
+            return DiskCacheLookup::Send(None);
+        };
+
+        if self.is_fresh(&entry) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return DiskCacheLookup::Fresh(entry.to_response());
+        }
+
+        add_validators(&entry, request);
+        DiskCacheLookup::Send(Some(entry))
+    }
+
+    /// Store the response of a request, or use the cached one if the server answered `304 Not Modified`
+    pub(crate) fn handle_response(
+        &self,
+        url: &str,
+        entry: Option<DiskCacheEntry>,
+        response: HttpResponse,
+    ) -> HttpResponse {
+        match entry {
+            Some(entry) if response.status == StatusCode::NOT_MODIFIED => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                let cached = entry.to_response();
+                self.refresh(entry, &response);
+                cached
+            }
+            _ => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                self.write(url, &response);
+                response
+            }
+        }
+    }
+
+    /// Read the cached response of a url. Unreadable entries are ignored
+    fn read(&self, url: &str) -> Option<DiskCacheEntry> {
+        let content = fs::read(self.entry_path(url)).ok()?;
+        let entry: DiskCacheEntry = serde_json::from_slice(&content).ok()?;
+
+        (entry.url == url).then_some(entry)
+    }
+
+    /// Cache a response if it is a successful JSON response.
+    ///
+    /// The cache is best effort, so write errors are ignored.
+    fn write(&self, url: &str, response: &HttpResponse) {
+        let is_json = response
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.contains("json"));
+
+        if !response.status.is_success() || !is_json {
+            return;
+        }
+
+        let Ok(body) = String::from_utf8(response.body.clone()) else {
+            return;
+        };
+
+        let entry = DiskCacheEntry {
+            url: url.to_string(),
+            response_url: response.url.clone(),
+            status: response.status.as_u16(),
+            etag: header_string(&response.headers, header::ETAG),
+            last_modified: header_string(&response.headers, header::LAST_MODIFIED),
+            stored_at: Utc::now(),
+            body,
+        };
+
+        let _ = self.write_entry(&entry);
+    }
+
+    fn write_entry(&self, entry: &DiskCacheEntry) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        // Write to a temporary file first, so readers never see a partial entry
+        let path = self.entry_path(&entry.url);
+        let tmp_path = path.with_extension(format!("tmp{}", fastrand::u64(..)));
+        fs::write(&tmp_path, serde_json::to_vec(entry)?)?;
+        fs::rename(&tmp_path, &path)
+    }
+
+    /// Whether a cached response can be used without revalidating it
+    fn is_fresh(&self, entry: &DiskCacheEntry) -> bool {
+        (Utc::now() - entry.stored_at)
+            .to_std()
+            .is_ok_and(|age| age < self.max_age)
+    }
+
+    /// Mark a revalidated entry as fresh again
+    fn refresh(&self, mut entry: DiskCacheEntry, response: &HttpResponse) {
+        entry.stored_at = Utc::now();
+        if let Some(etag) = header_string(&response.headers, header::ETAG) {
+            entry.etag = Some(etag);
+        }
+        if let Some(last_modified) = header_string(&response.headers, header::LAST_MODIFIED) {
+            entry.last_modified = Some(last_modified);
+        }
+
+        let _ = self.write_entry(&entry);
+    }
+}
+
+impl DiskCacheEntry {
+    /// Turn the cached entry back into a response
+    fn to_response(&self) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        HttpResponse {
+            status: StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK),
+            url: self.response_url.clone(),
+            headers,
+            body: self.body.clone().into_bytes(),
+        }
+    }
+}
+
+/// Add the revalidation headers of a stale entry to a request
+fn add_validators(entry: &DiskCacheEntry, request: &mut HttpRequest) {
+    let validators = [
+        (header::IF_NONE_MATCH, &entry.etag),
+        (header::IF_MODIFIED_SINCE, &entry.last_modified),
+    ];
+
+    for (name, value) in validators {
+        if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+            request.headers.insert(name, value);
+        }
+    }
+}
+
+fn header_string(headers: &HeaderMap, name: header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
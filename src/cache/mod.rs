@@ -0,0 +1,223 @@
+use core::num::NonZeroUsize;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use lru::LruCache;
+
+use crate::sync::lock;
+use crate::transport::HttpResponse;
+use crate::Path;
+
+#[cfg(feature = "disk_cache")]
+mod disk;
+#[cfg(feature = "disk_cache")]
+pub use disk::DiskCache;
+#[cfg(feature = "disk_cache")]
+pub(crate) use disk::DiskCacheLookup;
+
+/// An in-memory cache of the MusicBrainz API responses, used by the client for lookups, browses and searches.
+///
+/// Responses are keyed by their request url, and evicted once they are older than the TTL of their entity,
+/// or when the cache is full and they are the least recently used. Only successful responses are cached.
+///
+/// ## Example
+/// ```rust
+/// # use core::num::NonZeroUsize;
+/// # use core::time::Duration;
+/// # use musicbrainz_rs::cache::ResponseCache;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # use musicbrainz_rs::entity::release::Release;
+/// # fn main() -> Result<(), musicbrainz_rs::Error> {
+/// let cache = ResponseCache::new(NonZeroUsize::new(1000).unwrap())
+///     .with_ttl(Duration::from_secs(60 * 60))
+///     .with_entity_ttl::<Release>(Duration::from_secs(24 * 60 * 60));
+///
+/// let client = MusicBrainzClient::builder().response_cache(cache).build()?;
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ResponseCache {
+    entries: Mutex<LruCache<String, CacheEntry>>,
+
+    /// The TTL of the entities without a specific one
+    default_ttl: Duration,
+
+    /// The TTLs by entity path
+    entity_ttls: HashMap<&'static str, Duration>,
+
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    response: HttpResponse,
+    expires_at: Instant,
+}
+
+impl ResponseCache {
+    /// Create a cache holding at most `capacity` responses, kept for one hour
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            default_ttl: Duration::from_secs(60 * 60),
+            entity_ttls: HashMap::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Set how long the responses are kept, unless their entity has its own TTL
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Set how long the responses for the entity `T` are kept
+    pub fn with_entity_ttl<T: Path>(mut self, ttl: Duration) -> Self {
+        self.entity_ttls.insert(T::path(), ttl);
+        self
+    }
+
+    /// The TTL of the responses for an entity path, like `artist`
+    pub fn ttl(&self, entity: &str) -> Duration {
+        self.entity_ttls
+            .get(entity)
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    /// The number of requests answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// The number of requests that had to be sent
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// The number of cached responses, expired ones included
+    pub fn len(&self) -> usize {
+        lock(&self.entries).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all the cached responses
+    pub fn clear(&self) {
+        lock(&self.entries).clear();
+    }
+
+    /// Get the response of a url, if it is cached and still fresh
+    pub(crate) fn get(&self, url: &str) -> Option<HttpResponse> {
+        let mut entries = lock(&self.entries);
+
+        let response = match entries.get(url) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.response.clone()),
+            Some(_) => {
+                entries.pop(url);
+                None
+            }
+            None => None,
+        };
+
+        let counter = if response.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        response
+    }
+
+    /// Cache the response of a url, if it is successful
+    pub(crate) fn insert(&self, entity: &str, url: &str, response: &HttpResponse) {
+        let ttl = self.ttl(entity);
+
+        if !response.status.is_success() || ttl.is_zero() {
+            return;
+        }
+
+        lock(&self.entries).put(
+            url.to_string(),
+            CacheEntry {
+                response: response.clone(),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::artist::Artist;
+    use reqwest::StatusCode;
+
+    fn response(status: u16) -> HttpResponse {
+        HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            url: String::new(),
+            headers: Default::default(),
+            body: b"{}".to_vec(),
+        }
+    }
+
+    #[test]
+    fn should_count_hits_and_misses() {
+        let cache = ResponseCache::new(NonZeroUsize::new(10).unwrap());
+
+        assert!(cache.get("a").is_none());
+        cache.insert("artist", "a", &response(200));
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("a").is_some());
+
+        assert_eq!(cache.hits(), 2);
+        assert_eq!(cache.misses(), 1);
+    }
+
+    #[test]
+    fn should_evict_least_recently_used() {
+        let cache = ResponseCache::new(NonZeroUsize::new(2).unwrap());
+
+        cache.insert("artist", "a", &response(200));
+        cache.insert("artist", "b", &response(200));
+        cache.get("a");
+        cache.insert("artist", "c", &response(200));
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn should_expire_entries_by_entity() {
+        let cache = ResponseCache::new(NonZeroUsize::new(10).unwrap())
+            .with_ttl(Duration::from_secs(60))
+            .with_entity_ttl::<Artist>(Duration::ZERO);
+
+        cache.insert("artist", "a", &response(200));
+        cache.insert("release", "b", &response(200));
+
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert_eq!(cache.ttl("release"), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn should_not_cache_errors() {
+        let cache = ResponseCache::new(NonZeroUsize::new(10).unwrap());
+
+        cache.insert("artist", "a", &response(404));
+
+        assert!(cache.is_empty());
+    }
+}
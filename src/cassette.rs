@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::sync::lock;
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::Error;
+
+/// A file of recorded requests and responses, to run code calling the API without network access.
+///
+/// In record mode, the client sends its requests as usual and writes every response to the cassette.
+/// In replay mode, it never touches the network: each request is answered with the responses recorded
+/// for its url, in order. The last one is repeated once the others are consumed.
+///
+/// Only the method and url of the requests are recorded, so their headers (like the user agent) never end up in the file.
+///
+/// ## Example
+/// ```rust,no_run
+/// # use musicbrainz_rs::cassette::Cassette;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # fn main() -> Result<(), musicbrainz_rs::Error> {
+/// let mut client = MusicBrainzClient::default();
+///
+/// if std::env::var("RECORD").is_ok() {
+///     client.set_cassette(Cassette::record("tests/cassettes/nirvana.json"));
+/// } else {
+///     client.set_cassette(Cassette::replay("tests/cassettes/nirvana.json")?);
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send the requests and record their responses
+    Record,
+
+    /// Answer the requests with the recorded responses
+    Replay,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    /// All the interactions, in the order they were recorded
+    interactions: Vec<Interaction>,
+
+    /// The number of responses already replayed for each url
+    replayed: HashMap<String, usize>,
+}
+
+/// The content of a cassette file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    url: String,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RecordedBody {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl Cassette {
+    /// Record the responses to the file at `path`. An existing cassette is overwritten
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Replay the responses recorded in the file at `path`
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let content = fs::read(&path).map_err(Error::CassetteIo)?;
+        let file: CassetteFile = serde_json::from_slice(&content)?;
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Mutex::new(CassetteState {
+                interactions: file.interactions,
+                replayed: HashMap::new(),
+            }),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The file of the cassette
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Find the next recorded response for a request
+    pub(crate) fn replay_response(&self, request: &HttpRequest) -> Result<HttpResponse, Error> {
+        let mut state = lock(&self.state);
+        let method = request.method.as_str();
+
+        let matching: Vec<&Interaction> = state
+            .interactions
+            .iter()
+            .filter(|interaction| interaction.method == method && interaction.url == request.url)
+            .collect();
+
+        let replayed = state.replayed.get(&request.url).copied().unwrap_or(0);
+        let Some(interaction) = matching
+            .get(replayed)
+            .or(matching.last())
+            .map(|interaction| (*interaction).clone())
+        else {
+            return Err(Error::CassetteMiss(request.url.clone()));
+        };
+
+        *state.replayed.entry(request.url.clone()).or_default() += 1;
+        Ok(interaction.response.into())
+    }
+
+    /// Add a response to the cassette, and save it
+    pub(crate) fn record_response(&self, request: &HttpRequest, response: &HttpResponse) {
+        let mut state = lock(&self.state);
+
+        state.interactions.push(Interaction {
+            method: request.method.to_string(),
+            url: request.url.clone(),
+            response: response.into(),
+        });
+
+        // Recording is best effort, the request itself succeeded
+        let _ = self.save(&state.interactions);
+    }
+
+    fn save(&self, interactions: &[Interaction]) -> Result<(), Error> {
+        let file = CassetteFile {
+            interactions: interactions.to_vec(),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(Error::CassetteIo)?;
+        }
+        fs::write(&self.path, serde_json::to_vec_pretty(&file)?).map_err(Error::CassetteIo)
+    }
+}
+
+impl From<&HttpResponse> for RecordedResponse {
+    fn from(response: &HttpResponse) -> Self {
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+
+        let body = match String::from_utf8(response.body.clone()) {
+            Ok(text) => RecordedBody::Text(text),
+            Err(err) => RecordedBody::Bytes(err.into_bytes()),
+        };
+
+        Self {
+            status: response.status.as_u16(),
+            url: response.url.clone(),
+            headers,
+            body,
+        }
+    }
+}
+
+impl From<RecordedResponse> for HttpResponse {
+    fn from(response: RecordedResponse) -> Self {
+        let mut headers = HeaderMap::new();
+        for (name, value) in response.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(&value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+
+        Self {
+            status: StatusCode::from_u16(response.status).unwrap_or(StatusCode::OK),
+            url: response.url,
+            headers,
+            body: match response.body {
+                RecordedBody::Text(text) => text.into_bytes(),
+                RecordedBody::Bytes(bytes) => bytes,
+            },
+        }
+    }
+}
@@ -0,0 +1,332 @@
+#[cfg(feature = "rate_limit")]
+use core::num::NonZeroU32;
+use core::time::Duration;
+use std::sync::Arc;
+
+#[cfg(feature = "rate_limit")]
+use governor::{Quota, RateLimiter};
+use reqwest::header::HeaderValue;
+use reqwest::Proxy;
+
+#[cfg(feature = "disk_cache")]
+use crate::cache::DiskCache;
+use crate::cache::ResponseCache;
+use crate::cassette::Cassette;
+use crate::client::MusicBrainzClient;
+use crate::lenient::LenientMode;
+use crate::middleware::Middleware;
+use crate::retry::RetryPolicy;
+use crate::retry::DEFAULT_MAX_RETRIES;
+#[cfg(feature = "blocking")]
+use crate::transport::BlockingReqwestTransport;
+#[cfg(feature = "async")]
+use crate::transport::ReqwestTransport;
+use crate::Error;
+use crate::BASE_COVERART_URL;
+use crate::BASE_URL;
+use crate::DEFAULT_USER_AGENT;
+
+/// Build a [`MusicBrainzClient`] with a custom configuration.
+///
+/// Every setting is checked when calling [`build`](Self::build), and reported as an error if it is invalid.
+///
+/// ## Example
+/// ```rust
+/// # use core::time::Duration;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # fn main() -> Result<(), musicbrainz_rs::Error> {
+/// let client = MusicBrainzClient::builder()
+///     .user_agent("MyAwesomeTagger/1.2.0 ( http://myawesometagger.example.com )")
+///     .musicbrainz_url("https://test.musicbrainz.org/ws/2")
+///     .connect_timeout(Duration::from_secs(5))
+///     .read_timeout(Duration::from_secs(30))
+///     .build()?;
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MusicBrainzClientBuilder {
+    user_agent: String,
+    musicbrainz_url: String,
+    coverart_archive_url: String,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<String>,
+    #[cfg(any(feature = "default_tls", feature = "rustls"))]
+    root_certificates: Vec<Vec<u8>>,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    response_cache: Option<Arc<ResponseCache>>,
+    #[cfg(feature = "disk_cache")]
+    disk_cache: Option<Arc<DiskCache>>,
+    cassette: Option<Arc<Cassette>>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    lenient: Option<LenientMode>,
+    #[cfg(feature = "rate_limit")]
+    rate_limit: Option<Quota>,
+}
+
+impl Default for MusicBrainzClientBuilder {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            musicbrainz_url: BASE_URL.to_string(),
+            coverart_archive_url: BASE_COVERART_URL.to_string(),
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            #[cfg(any(feature = "default_tls", feature = "rustls"))]
+            root_certificates: Vec::new(),
+            // see : https://github.com/hyperium/hyper/issues/2136
+            pool_max_idle_per_host: 0,
+            pool_idle_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            response_cache: None,
+            #[cfg(feature = "disk_cache")]
+            disk_cache: None,
+            cassette: None,
+            middlewares: Vec::new(),
+            lenient: None,
+            #[cfg(feature = "rate_limit")]
+            rate_limit: Some(
+                Quota::per_second(NonZeroU32::new(1).unwrap())
+                    .allow_burst(NonZeroU32::new(5).unwrap()),
+            ),
+        }
+    }
+}
+
+impl MusicBrainzClientBuilder {
+    /// Set the user agent sent with each request. See [`MusicBrainzClient::set_user_agent`]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Set the root url of the MusicBrainz API. Defaults to `http://musicbrainz.org/ws/2`
+    pub fn musicbrainz_url(mut self, url: &str) -> Self {
+        self.musicbrainz_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the root url of the Cover Art Archive. Defaults to `http://coverartarchive.org`
+    pub fn coverart_archive_url(mut self, url: &str) -> Self {
+        self.coverart_archive_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for reading the response.
+    ///
+    /// The blocking client applies it to the whole request instead.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all the requests through an HTTP(S) proxy
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    /// Trust an additional root certificate, in PEM format
+    #[cfg(any(feature = "default_tls", feature = "rustls"))]
+    pub fn add_root_certificate(mut self, pem: &[u8]) -> Self {
+        self.root_certificates.push(pem.to_vec());
+        self
+    }
+
+    /// Set the maximum number of idle connections kept alive per host. Defaults to 0.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self
+    }
+
+    /// Set how long idle connections are kept alive
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how failed requests are retried. See [`RetryPolicy`]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Cache the lookup, browse and search responses. See [`ResponseCache`]
+    pub fn response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Store the raw responses on disk, and revalidate them once stale. See [`DiskCache`]
+    #[cfg(feature = "disk_cache")]
+    pub fn disk_cache(mut self, cache: DiskCache) -> Self {
+        self.disk_cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Record the responses to a cassette, or replay them from it. See [`Cassette`]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    /// Add a middleware, called around every request sent by the client. See [`Middleware`]
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Deserialize the responses leniently, instead of failing on the first unexpected value. See [`LenientMode`]
+    pub fn lenient(mut self, mode: LenientMode) -> Self {
+        self.lenient = Some(mode);
+        self
+    }
+
+    /// Set the quota of the client side rate limiter. Defaults to 1 request per second, with bursts of 5 requests.
+    #[cfg(feature = "rate_limit")]
+    pub fn rate_limit(mut self, quota: Quota) -> Self {
+        self.rate_limit = Some(quota);
+        self
+    }
+
+    /// Disable the client side rate limiter
+    #[cfg(feature = "rate_limit")]
+    pub fn no_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Check the configuration and create the client
+    #[allow(deprecated)]
+    pub fn build(self) -> Result<MusicBrainzClient, Error> {
+        HeaderValue::from_str(&self.user_agent)
+            .map_err(|_| Error::InvalidConfiguration("invalid user agent".to_string()))?;
+
+        for url in [&self.musicbrainz_url, &self.coverart_archive_url] {
+            reqwest::Url::parse(url).map_err(|err| {
+                Error::InvalidConfiguration(format!("invalid url `{url}`: {err}"))
+            })?;
+        }
+
+        let http_config = HttpConfig {
+            connect_timeout: self.connect_timeout,
+            read_timeout: self.read_timeout,
+            proxy: self.proxy.as_deref().map(Proxy::all).transpose()?,
+            #[cfg(any(feature = "default_tls", feature = "rustls"))]
+            root_certificates: self
+                .root_certificates
+                .iter()
+                .map(|pem| reqwest::Certificate::from_pem(pem))
+                .collect::<Result<_, _>>()?,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            pool_idle_timeout: self.pool_idle_timeout,
+        };
+
+        Ok(MusicBrainzClient {
+            musicbrainz_url: self.musicbrainz_url,
+            coverart_archive_url: self.coverart_archive_url,
+            user_agent: self.user_agent,
+            retry_policy: self.retry_policy,
+            max_retries: DEFAULT_MAX_RETRIES,
+            response_cache: self.response_cache,
+            #[cfg(feature = "disk_cache")]
+            disk_cache: self.disk_cache,
+
+            cassette: self.cassette,
+            middlewares: self.middlewares,
+            lenient: self.lenient,
+
+            #[cfg(feature = "async")]
+            transport: Arc::new(ReqwestTransport::new(http_config.async_client()?)),
+            #[cfg(feature = "async")]
+            in_flight: Default::default(),
+            #[cfg(feature = "blocking")]
+            blocking_transport: Arc::new(BlockingReqwestTransport::new(
+                http_config.blocking_client()?,
+            )),
+            #[cfg(feature = "rate_limit")]
+            rate_limit: self
+                .rate_limit
+                .map(|quota| Arc::new(RateLimiter::direct(quota))),
+        })
+    }
+}
+
+/// The settings of the reqwest clients, already validated
+#[derive(Debug, Clone)]
+struct HttpConfig {
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    #[cfg(any(feature = "default_tls", feature = "rustls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl HttpConfig {
+    #[cfg(feature = "async")]
+    fn async_client(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder =
+            reqwest::Client::builder().pool_max_idle_per_host(self.pool_max_idle_per_host);
+
+        // reqwest has its own defaults, which `None` would disable
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(any(feature = "default_tls", feature = "rustls"))]
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        builder.build()
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_client(&self) -> Result<reqwest::blocking::Client, reqwest::Error> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle_per_host);
+
+        // reqwest has its own defaults, which `None` would disable
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(any(feature = "default_tls", feature = "rustls"))]
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        // reqwest refuses to create a blocking client from inside an async runtime, which a
+        // separate thread is never part of
+        std::thread::scope(|scope| scope.spawn(|| builder.build()).join())
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
@@ -0,0 +1,89 @@
+use core::any::Any;
+use core::fmt::Debug;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::watch;
+
+use crate::sync::lock;
+
+/// The deserialized result of a request, shared with the identical requests
+pub(crate) type SharedValue = Arc<dyn Any + Send + Sync>;
+
+/// The state of an in-flight request: `None` while it is pending, then `Some` with its result,
+/// which is `None` if it failed
+type State = Option<Option<SharedValue>>;
+
+/// The requests currently sent by the async client, so identical concurrent requests are only sent once
+#[derive(Default)]
+pub(crate) struct InFlightRequests {
+    requests: Mutex<HashMap<String, watch::Receiver<State>>>,
+}
+
+pub(crate) enum InFlight<'a> {
+    /// No identical request is in flight, so this one must be sent
+    Leader(Leader<'a>),
+
+    /// An identical request is in flight, and its result can be awaited
+    Follower(watch::Receiver<State>),
+}
+
+/// The request actually sent. It stops being in flight once dropped
+pub(crate) struct Leader<'a> {
+    requests: &'a InFlightRequests,
+    url: String,
+    sender: watch::Sender<State>,
+}
+
+impl InFlightRequests {
+    /// Register a request for a url, or join the identical request already in flight
+    pub(crate) fn join(&self, url: &str) -> InFlight<'_> {
+        let mut requests = lock(&self.requests);
+
+        if let Some(receiver) = requests.get(url) {
+            return InFlight::Follower(receiver.clone());
+        }
+
+        let (sender, receiver) = watch::channel(None);
+        requests.insert(url.to_string(), receiver);
+
+        InFlight::Leader(Leader {
+            requests: self,
+            url: url.to_string(),
+            sender,
+        })
+    }
+}
+
+impl Debug for InFlightRequests {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InFlightRequests")
+            .field("requests", &lock(&self.requests).len())
+            .finish()
+    }
+}
+
+impl Leader<'_> {
+    /// Share the result of the request with the followers. `value` is only called if there are followers
+    pub(crate) fn complete(self, value: Option<impl FnOnce() -> SharedValue>) {
+        // The map holds a receiver too
+        if self.sender.receiver_count() > 1 {
+            let _ = self.sender.send(Some(value.map(|value| value())));
+        }
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        lock(&self.requests.requests).remove(&self.url);
+    }
+}
+
+/// Wait for the result of the request followed. Returns `None` if it failed or was cancelled
+pub(crate) async fn wait(mut receiver: watch::Receiver<State>) -> Option<SharedValue> {
+    receiver
+        .wait_for(Option::is_some)
+        .await
+        .ok()
+        .and_then(|state| state.clone().flatten())
+}
@@ -0,0 +1,710 @@
+use core::time::Duration;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
+use reqwest::header;
+
+#[cfg(feature = "rate_limit")]
+use governor::{
+    clock, middleware::NoOpMiddleware, state::InMemoryState, state::NotKeyed, RateLimiter,
+};
+use reqwest::header::InvalidHeaderValue;
+use serde::de::DeserializeOwned;
+
+use crate::cache::ResponseCache;
+#[cfg(feature = "disk_cache")]
+use crate::cache::{DiskCache, DiskCacheLookup};
+use crate::cassette::{Cassette, CassetteMode};
+use crate::entity::api::parse_response;
+use crate::entity::api::RawResponse;
+use crate::lenient::LenientMode;
+use crate::middleware::Middleware;
+use crate::query::ApiRequest;
+use crate::retry::RetryPolicy;
+use crate::retry::DEFAULT_MAX_RETRIES;
+#[cfg(feature = "blocking")]
+use crate::transport::BlockingTransport;
+#[cfg(feature = "async")]
+use crate::transport::Transport;
+use crate::transport::{HttpRequest, HttpResponse};
+#[cfg(all(test, feature = "async", feature = "rate_limit"))]
+use crate::HTTP_RATELIMIT_CODE;
+
+#[cfg(feature = "async")]
+use in_flight::{InFlight, InFlightRequests, SharedValue};
+
+mod builder;
+#[cfg(feature = "async")]
+mod in_flight;
+mod trace;
+pub use builder::MusicBrainzClientBuilder;
+
+/// The client used by the queries executed without an explicit client. See [`set_default_client`]
+static DEFAULT_CLIENT: OnceCell<MusicBrainzClient> = OnceCell::new();
+
+#[deprecated(
+    note = "use `default_client` instead, which can be configured with `set_default_client`"
+)]
+pub static MUSICBRAINZ_CLIENT: Lazy<MusicBrainzClient> = Lazy::new(|| default_client().clone());
+
+/// Install the client used by the queries executed without an explicit client, like
+/// [`FetchQuery::execute`](crate::FetchQuery::execute).
+///
+/// It can only be set once, before any query uses the default client. Otherwise, an
+/// [`InvalidConfiguration`](crate::Error::InvalidConfiguration) error is returned.
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// let client = MusicBrainzClient::builder()
+///     .user_agent("MyAwesomeTagger/1.2.0 ( http://myawesometagger.example.com )")
+///     .build()
+///     .unwrap();
+///
+/// musicbrainz_rs::set_default_client(client).unwrap();
+/// ```
+pub fn set_default_client(client: MusicBrainzClient) -> Result<(), crate::Error> {
+    DEFAULT_CLIENT.set(client).map_err(|_| {
+        crate::Error::InvalidConfiguration("the default client is already set".to_string())
+    })
+}
+
+/// The client used by the queries executed without an explicit client.
+///
+/// This is the client installed by [`set_default_client`], or [`MusicBrainzClient::default`] if none was.
+pub fn default_client() -> &'static MusicBrainzClient {
+    DEFAULT_CLIENT.get_or_init(MusicBrainzClient::default)
+}
+
+#[derive(Debug, Clone)]
+pub struct MusicBrainzClient {
+    pub musicbrainz_url: String,
+    pub coverart_archive_url: String,
+    user_agent: String,
+
+    /// How failed requests are retried. See [`RetryPolicy`]
+    pub retry_policy: RetryPolicy,
+
+    /// The maximum number of retries of a request.
+    ///
+    /// It replaces the `max_retries` of the [`retry_policy`](Self::retry_policy) when set to
+    /// something else than its default of 10.
+    #[deprecated(note = "use `retry_policy.max_retries` instead")]
+    pub max_retries: u32,
+
+    /// The cache of the lookup, browse and search responses. See [`ResponseCache`]
+    pub response_cache: Option<Arc<ResponseCache>>,
+
+    /// The on-disk cache of the raw responses. See [`DiskCache`]
+    #[cfg(feature = "disk_cache")]
+    pub disk_cache: Option<Arc<DiskCache>>,
+
+    /// The cassette recording or replaying the responses. See [`Cassette`]
+    cassette: Option<Arc<Cassette>>,
+
+    /// The hooks called around every request. See [`Middleware`]
+    middlewares: Vec<Arc<dyn Middleware>>,
+
+    /// Whether the responses are deserialized leniently. See [`LenientMode`]
+    lenient: Option<LenientMode>,
+
+    /// The HTTP stack used to send the requests. See [`Transport`]
+    #[cfg(feature = "async")]
+    pub(crate) transport: Arc<dyn Transport>,
+
+    /// The requests currently sent, shared with the identical requests.
+    ///
+    /// The clones share it until the way they send the requests changes, so they only get
+    /// the responses of their own transport and middlewares
+    #[cfg(feature = "async")]
+    in_flight: Arc<InFlightRequests>,
+
+    /// The HTTP stack used to send the blocking requests. See [`BlockingTransport`]
+    #[cfg(feature = "blocking")]
+    pub(crate) blocking_transport: Arc<dyn BlockingTransport>,
+
+    /// The rate limiter of the API. By default, it has 5 "Cells", and replenish 1 per second in accordance to the MB API guidelines.
+    ///
+    /// This allows "bursts" of 5 requests before limiting yourself to the API's classic rate.
+    /// So you may keep it in mind when designing your apps that you have 5 "free" requests
+    #[cfg(feature = "rate_limit")]
+    pub rate_limit:
+        Option<Arc<RateLimiter<NotKeyed, InMemoryState, clock::DefaultClock, NoOpMiddleware>>>,
+}
+
+// Common implements
+impl MusicBrainzClient {
+    /// Create a [`MusicBrainzClientBuilder`] to configure a new client
+    pub fn builder() -> MusicBrainzClientBuilder {
+        MusicBrainzClientBuilder::default()
+    }
+
+    /// Each request sent to MusicBrainz needs to include a User-Agent header,
+    /// with enough information in the User-Agent to contact the application maintainers.
+    /// We strongly suggest including your application's version number
+    /// in the User-Agent string too.
+    ///
+    /// For more info see [Rate Limiting](https://musicbrainz.org/doc/MusicBrainz_API/Rate_Limiting#Provide_meaningful_User-Agent_strings)
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use musicbrainz_rs::client::MusicBrainzClient;
+    /// # let mut client = MusicBrainzClient::default();
+    /// client.set_user_agent("MyAwesomeTagger/1.2.0 ( http://myawesometagger.example.com )");
+    /// ```
+    pub fn set_user_agent(&mut self, user_agent: &str) -> Result<(), InvalidHeaderValue> {
+        header::HeaderValue::from_str(user_agent)?;
+        self.user_agent = user_agent.to_string();
+
+        Ok(())
+    }
+
+    /// Replace the HTTP stack used to send the requests.
+    ///
+    /// The user agent, rate limit and retries are still handled by the client.
+    #[cfg(feature = "async")]
+    pub fn set_transport(&mut self, transport: impl Transport + 'static) {
+        self.transport = Arc::new(transport);
+        self.detach_in_flight();
+    }
+
+    /// Replace the HTTP stack used to send the blocking requests.
+    ///
+    /// The user agent and retries are still handled by the client.
+    #[cfg(feature = "blocking")]
+    pub fn set_blocking_transport(&mut self, transport: impl BlockingTransport + 'static) {
+        self.blocking_transport = Arc::new(transport);
+    }
+
+    /// Add a middleware, called around every request sent by the client. See [`Middleware`]
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.middlewares.push(Arc::new(middleware));
+        self.detach_in_flight();
+    }
+
+    /// Record the responses to a cassette, or replay them from it without sending any request.
+    ///
+    /// The rate limiter is skipped while replaying.
+    pub fn set_cassette(&mut self, cassette: Cassette) {
+        self.cassette = Some(Arc::new(cassette));
+        self.detach_in_flight();
+    }
+
+    /// The cassette recording or replaying the responses, if any
+    pub fn cassette(&self) -> Option<&Cassette> {
+        self.cassette.as_deref()
+    }
+
+    /// Deserialize the responses leniently, replacing the fields that can't be deserialized
+    /// instead of failing. See [`LenientMode`]
+    pub fn set_lenient(&mut self, mode: LenientMode) {
+        self.lenient = Some(mode);
+        self.detach_in_flight();
+    }
+
+    /// The lenient mode of the client, or `None` if it is strict
+    pub fn lenient(&self) -> Option<&LenientMode> {
+        self.lenient.as_ref()
+    }
+
+    /// Stop sharing the in-flight requests with the clones, as the responses may now differ
+    fn detach_in_flight(&mut self) {
+        #[cfg(feature = "async")]
+        {
+            self.in_flight = Default::default();
+        }
+    }
+
+    /// Remove the rate limiter
+    #[cfg(feature = "rate_limit")]
+    pub fn drop_ratelimit(&mut self) {
+        self.rate_limit = None;
+    }
+
+    /// Get the response of an API request from the cache
+    fn cached_response(&self, request: &ApiRequest) -> Option<HttpResponse> {
+        if request.no_cache {
+            return None;
+        }
+
+        self.response_cache.as_ref()?.get(&request.url)
+    }
+
+    /// Store the response of an API request in the cache
+    fn cache_response(&self, request: &ApiRequest, response: &HttpResponse) {
+        if request.no_cache {
+            return;
+        }
+
+        if let Some(cache) = &self.response_cache {
+            cache.insert(&request.entity, &request.url, response);
+        }
+    }
+
+    /// The retry policy of the requests, with the deprecated `max_retries` applied
+    #[allow(deprecated)]
+    fn effective_retry_policy(&self) -> Cow<'_, RetryPolicy> {
+        if self.max_retries == DEFAULT_MAX_RETRIES {
+            return Cow::Borrowed(&self.retry_policy);
+        }
+
+        Cow::Owned(RetryPolicy {
+            max_retries: self.max_retries,
+            ..self.retry_policy.clone()
+        })
+    }
+
+    /// Whether the responses are replayed from a cassette instead of being sent
+    fn is_replaying(&self) -> bool {
+        self.cassette
+            .as_deref()
+            .is_some_and(|cassette| cassette.mode() == CassetteMode::Replay)
+    }
+
+    /// Run the `before_request` hook of the middlewares, in order
+    fn before_request(&self, request: &mut HttpRequest) {
+        for middleware in &self.middlewares {
+            middleware.before_request(request);
+        }
+    }
+
+    /// Run the `after_response` or `on_error` hook of the middlewares, in reverse order
+    fn after_response(
+        &self,
+        request: &HttpRequest,
+        mut result: Result<HttpResponse, crate::Error>,
+        elapsed: Duration,
+    ) -> Result<HttpResponse, crate::Error> {
+        for middleware in self.middlewares.iter().rev() {
+            match &mut result {
+                Ok(response) => middleware.after_response(request, response, elapsed),
+                Err(err) => middleware.on_error(request, err, elapsed),
+            }
+        }
+
+        result
+    }
+
+    /// Create a GET request to the url, with the client's headers
+    pub(crate) fn build_request(&self, url: &str) -> HttpRequest {
+        let mut request = HttpRequest::get(url);
+
+        // The user agent has already been validated by `set_user_agent`
+        if let Ok(user_agent) = header::HeaderValue::from_str(&self.user_agent) {
+            request.headers.insert(header::USER_AGENT, user_agent);
+        }
+
+        request
+    }
+}
+
+// Requests
+#[cfg(feature = "blocking")]
+impl MusicBrainzClient {
+    /// Block the current thread until the rate limiter allows a new request
+    pub fn wait_for_ratelimit_blocking(&self) {
+        #[cfg(feature = "rate_limit")]
+        if let Some(val) = &self.rate_limit {
+            use governor::clock::Clock as _;
+
+            while let Err(not_until) = val.check() {
+                std::thread::sleep(not_until.wait_time_from(val.clock().now()));
+            }
+        }
+    }
+
+    /// Send the reqwest as a get, deal with the cache and retries
+    pub(crate) fn get_blocking<T>(&self, request: &ApiRequest) -> Result<T, crate::Error>
+    where
+        T: DeserializeOwned,
+    {
+        parse_response(
+            &request.url,
+            &self.response_blocking(request)?,
+            self.lenient.as_ref(),
+        )
+    }
+
+    /// Send the reqwest as a get, and keep the raw response along with the deserialized one
+    pub(crate) fn get_with_raw_blocking<T>(
+        &self,
+        request: &ApiRequest,
+    ) -> Result<(T, RawResponse), crate::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.response_blocking(request)?;
+        let value = parse_response(&request.url, &response, self.lenient.as_ref())?;
+
+        Ok((value, RawResponse::new(response)?))
+    }
+
+    /// Get the response of the request from the cache, or send it
+    fn response_blocking(&self, request: &ApiRequest) -> Result<HttpResponse, crate::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = trace::request_span(request).entered();
+
+        if let Some(response) = self.cached_response(request) {
+            return Ok(response);
+        }
+
+        let response =
+            self.send_cached_blocking(self.build_request(&request.url), request.no_cache)?;
+        self.cache_response(request, &response);
+        Ok(response)
+    }
+
+    /// Send a cover art request, deal with the disk cache and retries
+    pub(crate) fn get_coverart_blocking(
+        &self,
+        request: &ApiRequest,
+        skip_body: bool,
+    ) -> Result<HttpResponse, crate::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = trace::request_span(request).entered();
+
+        let mut http_request = self.build_request(&request.url);
+        http_request.skip_body = skip_body;
+        self.send_cached_blocking(http_request, request.no_cache)
+    }
+
+    /// Send the request through the disk cache, unless `no_cache` is set
+    #[cfg_attr(not(feature = "disk_cache"), allow(unused_mut, unused_variables))]
+    pub(crate) fn send_cached_blocking(
+        &self,
+        mut request: HttpRequest,
+        no_cache: bool,
+    ) -> Result<HttpResponse, crate::Error> {
+        #[cfg(feature = "disk_cache")]
+        if let Some(cache) = self.disk_cache.as_ref().filter(|_| !no_cache) {
+            let entry = match cache.lookup(&mut request) {
+                DiskCacheLookup::Fresh(response) => return Ok(response),
+                DiskCacheLookup::Send(entry) => entry,
+            };
+
+            let response = self.send_with_retries_blocking(request.clone())?;
+            return Ok(cache.handle_response(&request.url, entry, response));
+        }
+
+        self.send_with_retries_blocking(request)
+    }
+
+    /// Send the request a single time, or replay it from the cassette
+    fn send_once_blocking(&self, mut request: HttpRequest) -> Result<HttpResponse, crate::Error> {
+        self.before_request(&mut request);
+        let start = Instant::now();
+
+        let result = match self.cassette.as_deref() {
+            Some(cassette) if cassette.mode() == CassetteMode::Replay => {
+                cassette.replay_response(&request)
+            }
+            cassette => {
+                let result = self.blocking_transport.send(request.clone());
+                if let (Some(cassette), Ok(response)) = (cassette, &result) {
+                    cassette.record_response(&request, response);
+                }
+                result
+            }
+        };
+
+        self.after_response(&request, result, start.elapsed())
+    }
+
+    pub(crate) fn send_with_retries_blocking(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, crate::Error> {
+        use std::thread;
+        let retry_policy = self.effective_retry_policy();
+        let start = Instant::now();
+        let mut retries = 0;
+        let mut ratelimit_wait = Duration::ZERO;
+
+        loop {
+            if !self.is_replaying() {
+                let wait_start = Instant::now();
+                self.wait_for_ratelimit_blocking();
+                ratelimit_wait += wait_start.elapsed();
+            }
+
+            // Send the query
+            let result = self.send_once_blocking(request.clone());
+
+            // Let's check if the request failed in a way worth retrying
+            let Some(delay) = retry_policy.retry_delay(&result, retries, start.elapsed()) else {
+                trace::record_attempts(retries, ratelimit_wait);
+                // The last response is returned as is, to become the error matching its status
+                return result;
+            };
+
+            trace::backoff(&request, &result, retries, delay);
+            thread::sleep(delay);
+            retries += 1;
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl MusicBrainzClient {
+    pub async fn wait_for_ratelimit(&self) {
+        #[cfg(feature = "rate_limit")]
+        if let Some(val) = &self.rate_limit {
+            val.until_ready().await
+        }
+    }
+
+    /// Send the reqwest as a get, deal with the cache, ratelimits, and retries.
+    ///
+    /// Concurrent requests for the same url are only sent once, and share the deserialized result.
+    pub(crate) async fn get<T>(&self, request: &ApiRequest) -> Result<T, crate::Error>
+    where
+        T: DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        // A request skipping the caches asks for a fresh response, not one of another request
+        if request.no_cache {
+            return self.get_once(request).await;
+        }
+
+        let leader = match self.in_flight.join(&request.url) {
+            InFlight::Leader(leader) => leader,
+            InFlight::Follower(receiver) => {
+                let shared = in_flight::wait(receiver).await;
+                if let Some(value) = shared
+                    .as_deref()
+                    .and_then(|value| value.downcast_ref::<T>())
+                {
+                    return Ok(value.clone());
+                }
+
+                // The identical request failed or was cancelled, so let's send our own
+                return self.get_once(request).await;
+            }
+        };
+
+        let result = self.get_once(request).await;
+        leader.complete(
+            result
+                .as_ref()
+                .ok()
+                .map(|value: &T| || Arc::new(value.clone()) as SharedValue),
+        );
+
+        result
+    }
+
+    /// Send the reqwest as a get, without sharing it with identical requests
+    async fn get_once<T>(&self, request: &ApiRequest) -> Result<T, crate::Error>
+    where
+        T: DeserializeOwned,
+    {
+        parse_response(
+            &request.url,
+            &self.response(request).await?,
+            self.lenient.as_ref(),
+        )
+    }
+
+    /// Send the reqwest as a get, and keep the raw response along with the deserialized one.
+    ///
+    /// Unlike [`get`](Self::get), the request isn't shared with identical requests.
+    pub(crate) async fn get_with_raw<T>(
+        &self,
+        request: &ApiRequest,
+    ) -> Result<(T, RawResponse), crate::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.response(request).await?;
+        let value = parse_response(&request.url, &response, self.lenient.as_ref())?;
+
+        Ok((value, RawResponse::new(response)?))
+    }
+
+    /// Get the response of the request from the cache, or send it
+    async fn response(&self, request: &ApiRequest) -> Result<HttpResponse, crate::Error> {
+        let response = async {
+            if let Some(response) = self.cached_response(request) {
+                return Ok(response);
+            }
+
+            let response = self
+                .send_cached(self.build_request(&request.url), request.no_cache)
+                .await?;
+            self.cache_response(request, &response);
+            Ok::<_, crate::Error>(response)
+        };
+
+        #[cfg(feature = "tracing")]
+        let response = tracing::Instrument::instrument(response, trace::request_span(request));
+
+        response.await
+    }
+
+    /// Send a cover art request, deal with the disk cache, ratelimits, and retries
+    pub(crate) async fn get_coverart(
+        &self,
+        request: &ApiRequest,
+        skip_body: bool,
+    ) -> Result<HttpResponse, crate::Error> {
+        let mut http_request = self.build_request(&request.url);
+        http_request.skip_body = skip_body;
+        let response = self.send_cached(http_request, request.no_cache);
+
+        #[cfg(feature = "tracing")]
+        let response = tracing::Instrument::instrument(response, trace::request_span(request));
+
+        response.await
+    }
+
+    /// Send the request through the disk cache, unless `no_cache` is set
+    #[cfg_attr(not(feature = "disk_cache"), allow(unused_mut, unused_variables))]
+    pub(crate) async fn send_cached(
+        &self,
+        mut request: HttpRequest,
+        no_cache: bool,
+    ) -> Result<HttpResponse, crate::Error> {
+        #[cfg(feature = "disk_cache")]
+        if let Some(cache) = self.disk_cache.as_ref().filter(|_| !no_cache) {
+            let entry = match cache.lookup(&mut request) {
+                DiskCacheLookup::Fresh(response) => return Ok(response),
+                DiskCacheLookup::Send(entry) => entry,
+            };
+
+            let response = self.send_with_retries(request.clone()).await?;
+            return Ok(cache.handle_response(&request.url, entry, response));
+        }
+
+        self.send_with_retries(request).await
+    }
+
+    /// Send the request a single time, or replay it from the cassette
+    async fn send_once(&self, mut request: HttpRequest) -> Result<HttpResponse, crate::Error> {
+        self.before_request(&mut request);
+        let start = Instant::now();
+
+        let result = match self.cassette.as_deref() {
+            Some(cassette) if cassette.mode() == CassetteMode::Replay => {
+                cassette.replay_response(&request)
+            }
+            cassette => {
+                let result = self.transport.send(request.clone()).await;
+                if let (Some(cassette), Ok(response)) = (cassette, &result) {
+                    cassette.record_response(&request, response);
+                }
+                result
+            }
+        };
+
+        self.after_response(&request, result, start.elapsed())
+    }
+
+    /// Send the reqwest, deal with ratelimits, and retries
+    pub(crate) async fn send_with_retries(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, crate::Error> {
+        use tokio::time::sleep;
+        let retry_policy = self.effective_retry_policy();
+        let start = Instant::now();
+        let mut retries = 0;
+        let mut ratelimit_wait = Duration::ZERO;
+
+        loop {
+            if !self.is_replaying() {
+                let wait_start = Instant::now();
+                self.wait_for_ratelimit().await;
+                ratelimit_wait += wait_start.elapsed();
+            }
+
+            // Send the query
+            let result = self.send_once(request.clone()).await;
+
+            // Hard crash if the rate limit is hit while testing.
+            // It should be unacceptable to let the users hit it while we got a fancy system for it
+            #[cfg(all(test, feature = "async", feature = "rate_limit"))]
+            if self.rate_limit.is_some()
+                && result
+                    .as_ref()
+                    .is_ok_and(|response| response.status.as_u16() == HTTP_RATELIMIT_CODE)
+            {
+                panic!("Rate limit hit on rate limit feature!");
+            }
+
+            // Let's check if the request failed in a way worth retrying
+            let Some(delay) = retry_policy.retry_delay(&result, retries, start.elapsed()) else {
+                trace::record_attempts(retries, ratelimit_wait);
+                // The last response is returned as is, to become the error matching its status
+                return result;
+            };
+
+            trace::backoff(&request, &result, retries, delay);
+            sleep(delay).await;
+            retries += 1;
+        }
+    }
+}
+
+impl Default for MusicBrainzClient {
+    /// Create a client with the default configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the reqwest clients can't be created, like when the TLS backend can't be
+    /// initialized. Use [`MusicBrainzClient::builder`] to handle this error instead.
+    fn default() -> Self {
+        MusicBrainzClientBuilder::default()
+            .build()
+            .expect("Unable to build the default client")
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "async")]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::entity::artist::Artist;
+    use crate::transport::BoxFuture;
+    use crate::Fetch;
+
+    #[derive(Debug)]
+    struct NirvanaTransport;
+
+    impl Transport for NirvanaTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, crate::Error>> {
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    url: request.url,
+                    headers: request.headers,
+                    body: br#"{"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana"}"#
+                        .to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn should_execute_queries_with_the_default_client() {
+        let mut client = MusicBrainzClient::builder()
+            .user_agent("musicbrainz_rs_testing/0.9")
+            .build()
+            .unwrap();
+        client.set_transport(NirvanaTransport);
+        set_default_client(client).unwrap();
+
+        let nirvana = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(nirvana.name, "Nirvana");
+        assert_eq!(default_client().user_agent, "musicbrainz_rs_testing/0.9");
+        assert!(set_default_client(MusicBrainzClient::default()).is_err());
+    }
+}
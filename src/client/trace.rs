@@ -0,0 +1,56 @@
+use core::time::Duration;
+
+#[cfg(feature = "tracing")]
+use crate::query::ApiRequest;
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::Error;
+
+/// Create the span of an API request. The retries and rate limiter fields are recorded once it is done
+#[cfg(feature = "tracing")]
+pub(crate) fn request_span(request: &ApiRequest) -> tracing::Span {
+    tracing::info_span!(
+        "musicbrainz_request",
+        entity = %request.entity,
+        mbid = request.mbid.as_deref(),
+        includes = %request.includes,
+        url = %request.url,
+        retries = tracing::field::Empty,
+        ratelimit_wait_ms = tracing::field::Empty,
+    )
+}
+
+/// Record the number of retries and the time spent waiting on the rate limiter in the current request span
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn record_attempts(retries: u32, ratelimit_wait: Duration) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current()
+        .record("retries", retries)
+        .record("ratelimit_wait_ms", ratelimit_wait.as_millis() as u64);
+}
+
+/// Emit an event when a failed request is about to be retried
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn backoff(
+    request: &HttpRequest,
+    result: &Result<HttpResponse, Error>,
+    retries: u32,
+    delay: Duration,
+) {
+    #[cfg(feature = "tracing")]
+    match result {
+        Ok(response) => tracing::warn!(
+            url = %request.url,
+            status = response.status.as_u16(),
+            retries,
+            delay_ms = delay.as_millis() as u64,
+            "MusicBrainz returned a retryable status, backing off"
+        ),
+        Err(err) => tracing::warn!(
+            url = %request.url,
+            error = %err,
+            retries,
+            delay_ms = delay.as_millis() as u64,
+            "The request failed, backing off"
+        ),
+    }
+}
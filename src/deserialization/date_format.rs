@@ -3,26 +3,26 @@ use serde::{self, Deserialize, Deserializer};
 
 const FORMAT: &str = "%Y-%m-%d";
 
-/// Some times, music brainz dates doesn't have a month or day
-/// This artificially add january 1st before deserializing the API response
-pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+/// Deserialize an optional date, which may be missing its month or day.
+///
+/// Anything that isn't a date is read as `None`, without raising an error, so that the error paths
+/// tracked while deserializing aren't left pointing at a swallowed error.
+pub(crate) fn deserialize_opt<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    NaiveDate::parse_from_str(&s, FORMAT)
-        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01", &s), FORMAT))
-        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01-01", &s), FORMAT))
-        .map_err(serde::de::Error::custom)
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(parse(&s).ok()),
+        _ => Ok(None),
+    }
 }
 
-/// This is the `Option<NaiveDate>` version of the previous deserializer
-#[allow(clippy::unnecessary_wraps)]
-pub(crate) fn deserialize_opt<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    Ok(deserialize(deserializer).ok())
+/// Some times, music brainz dates doesn't have a month or day
+/// This artificially add january 1st before parsing them
+fn parse(s: &str) -> Result<NaiveDate, chrono::ParseError> {
+    NaiveDate::parse_from_str(s, FORMAT)
+        .or_else(|_err| NaiveDate::parse_from_str(&format!("{s}-01"), FORMAT))
+        .or_else(|_err| NaiveDate::parse_from_str(&format!("{s}-01-01"), FORMAT))
 }
 
 #[cfg(test)]
@@ -46,6 +46,8 @@ mod tests {
                 ended: Some(true),
                 begin: Some(NaiveDate::from_ymd_opt(1988, 1, 1).unwrap()),
                 end: Some(NaiveDate::from_ymd_opt(1994, 4, 5).unwrap()),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }
         )
     }
@@ -67,6 +69,8 @@ mod tests {
                 ended: Some(true),
                 begin: Some(NaiveDate::from_ymd_opt(1988, 1, 1).unwrap()),
                 end: None,
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }
         )
     }
@@ -87,6 +91,8 @@ mod tests {
                 ended: Some(true),
                 begin: Some(NaiveDate::from_ymd_opt(1988, 1, 1).unwrap()),
                 end: None,
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }
         )
     }
@@ -1,7 +1,10 @@
 use crate::entity::search::{SearchResult, Searchable};
 use chrono::NaiveDateTime;
 use serde::de::DeserializeOwned;
-use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::{
+    self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
 use std::fmt;
 use std::marker::PhantomData;
 
@@ -94,15 +97,17 @@ where
                 let offset = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(2, &self))?;
-                let entities = seq
+                let entities: Vec<Scored<T>> = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                let (scores, entities) = entities.into_iter().map(Scored::into_parts).unzip();
 
                 Ok(SearchResult {
                     created,
                     count,
                     offset,
                     entities,
+                    scores,
                 })
             }
 
@@ -114,7 +119,7 @@ where
                 let mut created: Option<String> = None;
                 let mut count: Option<i32> = None;
                 let mut offset: Option<i32> = None;
-                let mut entities: Option<Vec<T>> = None;
+                let mut entities: Option<Vec<Scored<T>>> = None;
 
                 while let Some(key) = map.next_key::<Field<T>>()? {
                     match key {
@@ -150,11 +155,13 @@ where
                 let offset = offset.ok_or_else(|| de::Error::missing_field("offset"))?;
 
                 let entities = entities.ok_or_else(|| de::Error::missing_field("entities"))?;
+                let (scores, entities) = entities.into_iter().map(Scored::into_parts).unzip();
                 Ok(SearchResult {
                     created,
                     count,
                     offset,
                     entities,
+                    scores,
                 })
             }
         }
@@ -169,3 +176,98 @@ where
         )
     }
 }
+
+/// A search hit: the entity, along with the `score` field the search API adds to it
+struct Scored<T> {
+    score: Option<u8>,
+    entity: T,
+}
+
+impl<T> Scored<T> {
+    fn into_parts(self) -> (Option<u8>, T) {
+        (self.score, self.entity)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Scored<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Scored<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScoredVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for ScoredVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Scored<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a search hit")
+            }
+
+            fn visit_map<V>(self, map: V) -> Result<Scored<T>, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut score = None;
+                let entity = T::deserialize(de::value::MapAccessDeserializer::new(ScoreMap {
+                    map,
+                    score: &mut score,
+                    at_score: false,
+                }))?;
+
+                Ok(Scored { score, entity })
+            }
+        }
+
+        deserializer.deserialize_map(ScoredVisitor(PhantomData))
+    }
+}
+
+/// Read the `score` of an entity on the way, while the entity itself is deserialized.
+/// The field is still given to the entity, as some of them (like tags) keep it.
+struct ScoreMap<'a, V> {
+    map: V,
+    score: &'a mut Option<u8>,
+    at_score: bool,
+}
+
+impl<'de, V> MapAccess<'de> for ScoreMap<'_, V>
+where
+    V: MapAccess<'de>,
+{
+    type Error = V::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some(key) = self.map.next_key::<String>()? else {
+            return Ok(None);
+        };
+
+        self.at_score = key == "score";
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        if !self.at_score {
+            return self.map.next_value_seed(seed);
+        }
+
+        let value: serde_json::Value = self.map.next_value()?;
+        *self.score = value.as_u64().and_then(|score| u8::try_from(score).ok());
+        seed.deserialize(value).map_err(de::Error::custom)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
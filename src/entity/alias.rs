@@ -1,4 +1,5 @@
 use crate::date_format;
+use crate::Mbid;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
@@ -24,5 +25,10 @@ pub struct Alias {
     #[serde(rename = "type")]
     pub alias_type: Option<String>,
     pub primary: Option<bool>,
-    pub type_id: Option<String>,
+    pub type_id: Option<Mbid>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
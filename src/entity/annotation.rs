@@ -25,6 +25,11 @@ pub struct Annotation {
     /// the annotated entity's entity type
     #[serde(rename = "type")]
     pub annotation_type: String,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -1,6 +1,16 @@
+use reqwest::header;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
+use crate::error::ApiError;
+use crate::error::DeserializationError;
+use crate::lenient;
+use crate::lenient::LenientMode;
+use crate::transport::HttpResponse;
 use crate::Error;
 
 /// Represent a result coming directly from the API.
@@ -12,31 +22,148 @@ pub enum MusicbrainzResult<T> {
 }
 
 impl<T> MusicbrainzResult<T> {
-    pub fn into_result(self, query: String) -> Result<T, Error> {
+    /// Turn the result into the entity, or the error matching the response it comes from
+    pub fn into_result(self, url: &str, response: &HttpResponse) -> Result<T, Error> {
         match self {
             Self::Ok(val) => Ok(val),
-            Self::Err(err) => Err(err.into_error(query)),
+            Self::Err(err) => Err(err.into_error(url, response)),
         }
     }
 }
 
+/// A response of the API, before it gets deserialized into an entity.
+///
+/// It gives access to the fields that the entities don't model yet, and to the response headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawResponse {
+    /// The HTTP status of the response
+    pub status: StatusCode,
+
+    /// The url the response comes from
+    pub url: String,
+
+    /// The headers of the response
+    pub headers: HeaderMap,
+
+    /// The body of the response, or null if it is empty
+    pub json: Value,
+}
+
+impl RawResponse {
+    pub(crate) fn new(response: HttpResponse) -> Result<Self, Error> {
+        // The body of the cover art images isn't read
+        let json = if response.body.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&response.body)?
+        };
+
+        Ok(Self {
+            json,
+            status: response.status,
+            url: response.url,
+            headers: response.headers,
+        })
+    }
+}
+
 /// An error given by musicbrainz's API.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct MusicbrainzError {
-    error: String,
-    help: String,
+    /// The error message
+    pub error: String,
+
+    /// A pointer to the documentation of the API
+    #[serde(default)]
+    pub help: String,
 }
 
 impl MusicbrainzError {
-    pub fn into_error(self, query: String) -> Error {
-        if self.is_not_found() {
-            return Error::NotFound(query);
+    /// Turn the error into the [`Error`] matching the status of the response it comes from
+    pub fn into_error(self, url: &str, response: &HttpResponse) -> Error {
+        ApiError {
+            status: response.status,
+            url: url.to_string(),
+            message: self.error,
+            body: response.body.clone(),
         }
-
-        Error::MusicbrainzError(self)
+        .into_error()
     }
 
     pub fn is_not_found(&self) -> bool {
         self.error == "Not Found"
     }
 }
+
+/// Turn the response into the matching error if the request failed
+pub(crate) fn error_for_status(url: &str, response: &HttpResponse) -> Result<(), Error> {
+    if response.status.is_success() {
+        return Ok(());
+    }
+
+    // Error responses may not come from Musicbrainz itself, like the HTML pages of a proxy
+    match serde_json::from_slice::<MusicbrainzError>(&response.body) {
+        Ok(err) => Err(err.into_error(url, response)),
+        Err(_) => Err(ApiError {
+            status: response.status,
+            url: url.to_string(),
+            message: response
+                .status
+                .canonical_reason()
+                .unwrap_or("Unknown error")
+                .to_string(),
+            body: response.body.clone(),
+        }
+        .into_error()),
+    }
+}
+
+/// Deserialize the body of an API response, or turn it into an error if the request failed
+pub(crate) fn parse_response<T>(
+    url: &str,
+    response: &HttpResponse,
+    lenient: Option<&LenientMode>,
+) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    error_for_status(url, response)?;
+
+    let api_error = |message: String| ApiError {
+        status: response.status,
+        url: url.to_string(),
+        message,
+        body: response.body.clone(),
+    };
+
+    // Transports are allowed to leave out the content type, so only a wrong one is rejected
+    if let Some(content_type) = response
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|content_type| !content_type.contains("json"))
+    {
+        return Err(Error::UnexpectedContentType(api_error(format!(
+            "expected JSON, got \"{content_type}\""
+        ))));
+    }
+
+    // Deserializing the entity first keeps the path of the failing field, which an untagged
+    // `MusicbrainzResult` would hide
+    let deserializer = &mut serde_json::Deserializer::from_slice(&response.body);
+    let result = serde_path_to_error::deserialize(deserializer).or_else(|err| match lenient {
+        Some(mode) => lenient::deserialize(mode, url, &response.body, err),
+        None => Err(err),
+    });
+
+    result.map_err(
+        |err| match serde_json::from_slice::<MusicbrainzError>(&response.body) {
+            Ok(api_error) => api_error.into_error(url, response),
+            Err(_) => Error::Deserialization(Box::new(DeserializationError::new(
+                url,
+                &response.body,
+                err,
+            ))),
+        },
+    )
+}
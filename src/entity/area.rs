@@ -8,27 +8,28 @@ use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
 
+use crate::Mbid;
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
 
 /// Areas are historical and existing geographic regions. Areas include countries, sub-divisions,
 /// counties, municipalities, cities, districts and islands.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[cfg_attr(
     feature = "legacy_serialize",
     serde(rename_all(deserialize = "kebab-case"))
 )]
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
-#[serde(default)]
 pub struct Area {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The type of area. Possible values are: Country, Subdivision, County, Municipality, City,
     /// District, Island.
     #[serde(rename = "type")]
     pub area_type: Option<AreaType>,
     /// The name of the area.
+    #[serde(default)]
     pub name: String,
     pub relations: Option<Vec<Relation>>,
     /// The ISO 3166 codes are the codes assigned by ISO to countries and subdivisions.
@@ -40,12 +41,19 @@ pub struct Area {
     pub annotation: Option<String>,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
-    pub type_id: Option<String>,
+    pub type_id: Option<Mbid>,
+    #[serde(default)]
     pub sort_name: String,
     pub life_span: Option<LifeSpan>,
     pub tags: Option<Vec<Tag>>,
     pub genres: Option<Vec<Genre>>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of a MusicBrainz area entity.
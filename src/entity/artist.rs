@@ -13,6 +13,7 @@ use crate::entity::work::Work;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
@@ -21,25 +22,27 @@ use serde::{Deserialize, Serialize};
 /// professional (like a producer or engineer). Occasionally, it can also be a non-musical person
 /// (like a photographer, an illustrator, or a poet whose writings are set to music), or even a
 /// fictional character. For some other special cases, see special purpose artists.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[cfg_attr(
     feature = "legacy_serialize",
     serde(rename_all(deserialize = "kebab-case"))
 )]
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
-#[serde(default)]
 pub struct Artist {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The official name of an artist, be it a person or a band.
+    #[serde(default)]
     pub name: String,
     /// The sort name is a variant of the artist name which would be used when sorting artists by
     /// name, such as in record shops or libraries. Among other things, sort names help to ensure
     /// that all the artists that start with "The" don't end up up under "T". The guidelines for
     /// sort names are the best place to check for more specific usage info.
+    #[serde(default)]
     pub sort_name: String,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
 
     /// The type is used to state whether an artist is a person, a group, or something else.
@@ -106,7 +109,13 @@ pub struct Artist {
     ///    - For others
     ///        There are no clear indications about how to use dates for artists of the type Other at
     ///        the moment.
+    #[allow(clippy::doc_overindented_list_items)]
     pub life_span: Option<LifeSpan>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of a MusicBrainz artist entity.
@@ -9,4 +9,9 @@ pub struct ArtistCredit {
     pub name: String,
     pub joinphrase: Option<String>,
     pub artist: Artist,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
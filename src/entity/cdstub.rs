@@ -21,6 +21,11 @@ pub struct CDStub {
     pub barcode: Option<String>,
     /// the disambiguation string set on the CD stub
     pub disambiguation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -16,6 +16,11 @@ pub struct Discid {
     pub sectors: u32,
     pub offsets: Vec<u32>,
     pub releases: Option<Vec<Release>>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -30,6 +35,11 @@ pub struct Disc {
     pub offset_count: u32,
     pub sectors: u32,
     pub offsets: Vec<u32>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl_includes!(
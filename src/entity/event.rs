@@ -8,6 +8,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
@@ -60,7 +61,7 @@ pub enum EventType {
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Event {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
     /// The name is the official name of the event if it has one, or a descriptive name (like
     /// "Main Artist at Place") if not.
@@ -124,6 +125,7 @@ pub struct Event {
     ///    - For others
     ///        There are no clear indications about how to use dates for artists of the type Other at
     ///        the moment.
+    #[allow(clippy::doc_overindented_list_items)]
     pub life_span: Option<LifeSpan>,
 
     /// The disambiguation comments are fields in the database used to help distinguish identically
@@ -131,7 +133,12 @@ pub struct Event {
     /// also appear in the search results next to their names.
     pub disambiguation: Option<String>,
 
-    pub type_id: Option<String>,
+    pub type_id: Option<Mbid>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -14,6 +14,11 @@ pub struct Genre {
     pub count: Option<u32>,
     pub name: String,
     pub disambiguation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 // TODO: Genre browsing
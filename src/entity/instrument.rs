@@ -6,6 +6,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use lucene_query_builder::QueryBuilder;
@@ -23,7 +24,7 @@ use lucene_query_builder::QueryBuilder;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Instrument {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The instrument name is the name of the instrument, typically the most common name in English.
     pub name: String,
     /// The type categorises the instrument by the way the sound is created, similar to the
@@ -31,7 +32,7 @@ pub struct Instrument {
     /// Precussion instrument, Electronic instrument, Family, Ensemble, Other instrument.
     #[serde(rename = "type")]
     pub instrument_type: InstrumentType,
-    pub type_id: String,
+    pub type_id: Mbid,
     /// The description is a brief description of the main characteristics of the instrument.
     pub description: Option<String>,
     /// The disambiguation comments are fields in the database used to help distinguish identically
@@ -46,6 +47,11 @@ pub struct Instrument {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -8,6 +8,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use lucene_query_builder::QueryBuilder;
@@ -24,8 +25,8 @@ use lucene_query_builder::QueryBuilder;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Label {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
-    pub type_id: Option<String>,
+    pub id: Mbid,
+    pub type_id: Option<Mbid>,
     /// The type describes the main activity of the label.
     #[serde(rename = "type")]
     pub label_type: Option<LabelType>,
@@ -48,6 +49,11 @@ pub struct Label {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -104,6 +110,11 @@ pub struct LabelInfo {
     /// The label of the release. Note: It is possible to insert a catalog number without setting a label.
     /// So the option is not safe to unwrap even when fetching all the relations
     pub label: Option<Label>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of a MusicBrainz label entity.
@@ -18,7 +18,7 @@ use crate::entity::url::Url;
 use crate::entity::work::Work;
 use crate::Fetch;
 use crate::Path;
-use crate::{Browse, Search};
+use crate::{Browse, Search, SearchByIds};
 use crate::{CoverartQuery, FetchCoverart, FetchCoverartQuery};
 use serde::Serialize;
 #[cfg(not(feature = "legacy_serialize"))]
@@ -39,32 +39,61 @@ macro_rules! impl_includes {
                    self
                })*
             }
+
+        impl crate::FetchManyQuery<$ty> {
+               $(pub fn $args(&mut self) -> &mut Self  {
+                     self.query.0.include = self.query.0.include($inc).include.to_owned();
+                   self
+               })*
+            }
         }
 }
 
 macro_rules! impl_browse {
     ($ty: ty, $(($args:ident, $browse: expr)),+) => {
         impl crate::BrowseQuery<$ty> {
-               $(pub fn $args(&mut self, id: &str) -> &mut Self  {
-                    use std::fmt::Write as _;
-                    let _ = write!(self.id, "{}={}", $browse.as_str(), id);
+               $(pub fn $args(&mut self, id: impl AsRef<str>) -> &mut Self  {
+                    self.by = Some(($browse.as_str(), id.as_ref().to_string()));
                     self
                })*
             }
         }
 }
 
+macro_rules! impl_search_by_ids {
+    ($(($ty: ty, $query: ty, $field: ident)),+) => {
+        $(impl SearchByIds for $ty {
+            fn ids_query(ids: &[String]) -> String {
+                let mut query = <$query>::query_builder();
+                for (i, id) in ids.iter().enumerate() {
+                    if i > 0 {
+                        query.or();
+                    }
+                    query.$field(id);
+                }
+                query.build()
+            }
+
+            fn mbid(&self) -> &str {
+                self.id.as_str()
+            }
+        })+
+    }
+}
+
 macro_rules! impl_fetchcoverart {
     ($($t: ty), +) => {
         $(impl FetchCoverart for $t {
             fn get_coverart(&self) -> FetchCoverartQuery<Self> {
                 let mut coverart_query = FetchCoverartQuery(CoverartQuery {
                     path: Self::path().to_string(),
+                    id: None,
                     phantom: PhantomData,
                     target: CoverartTarget {
                         img_type: None,
                         img_res: None,
                     },
+                    no_cache: false,
                 });
                 coverart_query.id(&self.id);
                 coverart_query
@@ -140,6 +169,19 @@ impl Search for Series {}
 impl Search for Work {}
 impl Search for CDStub {}
 
+impl_search_by_ids!(
+    (Area, area::AreaSearchQuery, aid),
+    (Artist, artist::ArtistSearchQuery, arid),
+    (Event, event::EventSearchQuery, eid),
+    (Instrument, InstrumentSearchQuery, iid),
+    (Label, label::LabelSearchQuery, laid),
+    (Recording, recording::RecordingSearchQuery, rid),
+    (Release, release::ReleaseSearchQuery, reid),
+    (ReleaseGroup, release_group::ReleaseGroupSearchQuery, rgid),
+    (Series, series::SeriesSearchQuery, sid),
+    (Work, work::WorkSearchQuery, wid)
+);
+
 impl Path for Annotation {
     fn path() -> &'static str {
         "annotation"
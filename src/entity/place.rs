@@ -8,6 +8,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt;
@@ -20,7 +21,7 @@ use std::fmt;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Place {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The place name is the official name of a place.
     pub name: String,
     /// The type categorises the place based on its primary function. The possible values are:
@@ -28,7 +29,7 @@ pub struct Place {
     /// Pressing plant, Other.
     #[serde(rename = "type")]
     pub place_type: Option<PlaceType>,
-    pub type_id: Option<String>,
+    pub type_id: Option<Mbid>,
     pub life_span: Option<LifeSpan>,
     /// The latitude and longitude describe the location of the place using geographic coordinates.
     pub coordinates: Option<Coordinates>,
@@ -49,12 +50,22 @@ pub struct Place {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Coordinates {
     pub latitude: Coordinate,
     pub longitude: Coordinate,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Place coordinate (e.g., latitude or longitude).
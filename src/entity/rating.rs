@@ -9,4 +9,9 @@ use serde::{Deserialize, Serialize};
 pub struct Rating {
     pub vote_count: Option<u32>,
     pub value: Option<f32>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
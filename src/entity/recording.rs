@@ -10,6 +10,7 @@ use crate::entity::BrowseBy;
 use crate::entity::{Include, Relationship, Subquery};
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
@@ -31,7 +32,7 @@ use lucene_query_builder::QueryBuilder;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Recording {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The title of the recording.
     pub title: String,
 
@@ -65,6 +66,11 @@ pub struct Recording {
     #[serde(deserialize_with = "date_format::deserialize_opt")]
     #[serde(default)]
     pub first_release_date: Option<NaiveDate>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
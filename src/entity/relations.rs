@@ -10,6 +10,7 @@ use crate::entity::release_group::ReleaseGroup;
 use crate::entity::series::Series;
 use crate::entity::url::Url;
 use crate::entity::work::Work;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
@@ -35,7 +36,7 @@ pub struct Relation {
     #[serde(flatten)]
     pub content: RelationContent,
     pub attribute_values: Option<HashMap<String, String>>,
-    pub attribute_ids: Option<HashMap<String, String>>,
+    pub attribute_ids: Option<HashMap<String, Mbid>>,
     /// There are a huge number of different relationship types. The lists (organised per types of
     /// entities they connect) can be checked at the
     /// [relationship type table](https://musicbrainz.org/relationships).
@@ -45,7 +46,7 @@ pub struct Relation {
     pub target_credit: Option<String>,
     pub source_credit: Option<String>,
     pub ended: Option<bool>,
-    pub type_id: String,
+    pub type_id: Mbid,
     #[serde(default)]
     #[serde(deserialize_with = "date_format::deserialize_opt")]
     /// Some relationships have two date fields, a begin date and an end date, to store the period
@@ -59,6 +60,11 @@ pub struct Relation {
 
     /// The order of the relationship
     pub ordering_key: Option<u64>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -1,3 +1,4 @@
+use crate::Mbid;
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
@@ -41,13 +42,13 @@ use crate::query::relations::impl_relations_includes;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Release {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
     /// The title of the release.
     pub title: String,
 
     #[serde(rename = "status-id")]
-    pub status_id: Option<String>,
+    pub status_id: Option<Mbid>,
 
     /// The status describes how "official" a release is.
     pub status: Option<ReleaseStatus>,
@@ -73,7 +74,7 @@ pub struct Release {
     pub disambiguation: Option<String>,
 
     #[serde(rename = "packaging-id")]
-    pub packaging_id: Option<String>,
+    pub packaging_id: Option<Mbid>,
 
     /// The physical packaging that accompanies the release. See the
     /// [list of packaging](https://musicbrainz.org/doc/Release/Packaging) for more information.
@@ -103,6 +104,11 @@ pub struct Release {
 
     /// The text representation on the release.
     pub text_representation: Option<ReleaseTextRepresentation>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -113,6 +119,11 @@ pub struct ReleaseTextRepresentation {
     /// The script used to write the release's track list. The possible values are taken from the
     /// ISO 15924 standard.
     pub script: Option<ReleaseScript>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The script used to write the release's track list. The possible values are taken from the
@@ -1921,10 +1932,15 @@ pub struct Media {
     pub position: Option<u32>,
     pub track_count: u32,
     pub disc_count: Option<u32>,
-    pub format_id: Option<String>,
+    pub format_id: Option<Mbid>,
     pub format: Option<String>,
     pub tracks: Option<Vec<Track>>,
     pub track_offset: Option<u32>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A track is the way a recording is represented on a particular release (or, more exactly, on a
@@ -1941,8 +1957,13 @@ pub struct Track {
     pub number: String,
     pub length: Option<u32>,
     pub position: u32,
-    pub id: String,
+    pub id: Mbid,
     pub artist_credit: Option<Vec<ArtistCredit>>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -10,41 +10,45 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
 
 /// A release group, just as the name suggests, is used to group several different releases into a
 /// single logical entity. Every release belongs to one, and only one release group.
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[cfg_attr(
     feature = "legacy_serialize",
     serde(rename_all(deserialize = "kebab-case"))
 )]
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
-#[serde(default)]
 pub struct ReleaseGroup {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
-    pub primary_type_id: Option<String>,
+    pub primary_type_id: Option<Mbid>,
 
     /// The type describes what kind of releases the release group represents, for example album,
     /// single, soundtrack, compilation etc.
     /// See the Type subpage for a full list of release group types.
     pub primary_type: Option<ReleaseGroupPrimaryType>,
 
-    pub secondary_type_ids: Vec<String>,
+    #[serde(default)]
+    pub secondary_type_ids: Vec<Mbid>,
+    #[serde(default)]
     pub secondary_types: Vec<ReleaseGroupSecondaryType>,
 
-    #[serde(deserialize_with = "date_format::deserialize_opt")]
+    #[serde(default, deserialize_with = "date_format::deserialize_opt")]
     pub first_release_date: Option<NaiveDate>,
 
     /// The title of a release group is usually very similar, if not the same, as the titles of the
     /// releases contained within it.
+    #[serde(default)]
     pub title: String,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
     /// Relationships are a way to represent all the different ways in which entities are connected
     /// to each other and to URLs outside MusicBrainz.
@@ -63,6 +67,11 @@ pub struct ReleaseGroup {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The primary type of a MusicBrainz release group.
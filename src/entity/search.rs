@@ -29,6 +29,15 @@ pub struct SearchResult<T> {
     pub count: i32,
     pub offset: i32,
     pub entities: Vec<T>,
+    /// The relevance score (0-100) of each entity, in the same order as `entities`
+    pub scores: Vec<Option<u8>>,
+}
+
+impl<T> SearchResult<T> {
+    /// The entities along with their relevance score
+    pub fn hits(&self) -> impl Iterator<Item = (&T, Option<u8>)> {
+        self.entities.iter().zip(self.scores.iter().copied())
+    }
 }
 
 #[cfg(not(feature = "legacy_serialize"))]
@@ -45,16 +54,54 @@ where
         map.serialize_entry(T::CREATED_FIELD, &self.created)?;
         map.serialize_entry(T::COUNT_FIELD, &self.count)?;
         map.serialize_entry(T::OFFSET_FIELD, &self.offset)?;
-        map.serialize_entry(T::ENTITIES_FIELD, &self.entities)?;
+        map.serialize_entry(
+            T::ENTITIES_FIELD,
+            &ScoredEntities(&self.entities, &self.scores),
+        )?;
         map.end()
     }
 }
 
+/// Serialize the entities with their score, the way the search API returns them
+#[cfg(not(feature = "legacy_serialize"))]
+struct ScoredEntities<'a, T>(&'a [T], &'a [Option<u8>]);
+
+#[cfg(not(feature = "legacy_serialize"))]
+impl<T> Serialize for ScoredEntities<'_, T>
+where
+    T: Searchable + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.0.iter().enumerate().map(|(i, entity)| ScoredEntity {
+            entity,
+            score: self.1.get(i).copied().flatten().filter(|_| !T::KEEPS_SCORE),
+        }))
+    }
+}
+
+/// An entity with the `score` field added by the search API
+#[cfg(not(feature = "legacy_serialize"))]
+#[derive(Serialize)]
+struct ScoredEntity<'a, T> {
+    #[serde(flatten)]
+    entity: &'a T,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<u8>,
+}
+
 pub trait Searchable {
     const CREATED_FIELD: &'static str;
     const COUNT_FIELD: &'static str;
     const OFFSET_FIELD: &'static str;
     const ENTITIES_FIELD: &'static str;
+
+    /// Whether the entities keep the `score` of the search results themselves, so that it isn't
+    /// serialized twice. The fields that aren't modelled keep it with the `extra_fields` feature
+    const KEEPS_SCORE: bool = cfg!(feature = "extra_fields");
 }
 
 impl Searchable for Annotation {
@@ -139,6 +186,7 @@ impl Searchable for Tag {
     const COUNT_FIELD: &'static str = "count";
     const OFFSET_FIELD: &'static str = "offset";
     const ENTITIES_FIELD: &'static str = "tags";
+    const KEEPS_SCORE: bool = true;
 }
 
 impl Searchable for Url {
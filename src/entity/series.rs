@@ -6,6 +6,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use lucene_query_builder::QueryBuilder;
@@ -20,7 +21,7 @@ use lucene_query_builder::QueryBuilder;
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Series {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The series name is the official name of the series.
     pub name: String,
     #[serde(rename = "type")]
@@ -32,7 +33,7 @@ pub struct Series {
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
     pub disambiguation: String,
-    pub type_id: String,
+    pub type_id: Mbid,
     /// Relationships are a way to represent all the different ways in which entities are connected
     /// to each other and to URLs outside MusicBrainz.
     pub relations: Option<Vec<Relation>>,
@@ -45,6 +46,11 @@ pub struct Series {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of a MusicBrainz series entity.
@@ -5,4 +5,9 @@ pub struct Tag {
     pub name: String,
     pub count: Option<i32>,
     pub score: Option<i32>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,6 +1,7 @@
 use super::{Include, Relationship};
 use crate::entity::tag::Tag;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 /// A URL in MusicBrainz is a specific entity representing a regular internet Uniform Resource Locator.
@@ -12,9 +13,14 @@ use serde::{Deserialize, Serialize};
 /// server to see all types.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct Url {
-    pub id: String,
+    pub id: Mbid,
     pub resource: String,
     pub tags: Option<Vec<Tag>>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 // Relationships includes
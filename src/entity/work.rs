@@ -7,6 +7,7 @@ use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use crate::query::browse::impl_browse_includes;
 use crate::query::relations::impl_relations_includes;
+use crate::Mbid;
 use serde::{Deserialize, Serialize};
 
 use lucene_query_builder::QueryBuilder;
@@ -402,6 +403,20 @@ pub enum WorkAttribute {
     UnrecognizedAttribute,
 }
 
+/// An attribute of a work, along with the fields of the API that the [`WorkAttribute`] doesn't model,
+/// like its `type-id` and `value-id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkAttributeEntry {
+    /// The type and value of the attribute
+    #[serde(flatten)]
+    pub attribute: WorkAttribute,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 /// The musical key and mode associated with a work
 ///
 /// Marked as `non_exhaustive` because it is conceivable that MusicBrainz would add more musical modes.
@@ -610,9 +625,9 @@ impl From<MusicalKey> for String {
 #[cfg_attr(not(feature = "legacy_serialize"), serde(rename_all = "kebab-case"))]
 pub struct Work {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     pub title: String,
-    pub type_id: Option<String>,
+    pub type_id: Option<Mbid>,
     /// Works are represented predominantly at two levels: Discrete works, Aggregate works.
     #[serde(rename = "type")]
     pub work_type: Option<WorkType>,
@@ -620,7 +635,7 @@ pub struct Work {
     pub language: Option<String>,
     pub languages: Option<Vec<String>>,
     pub iswcs: Option<Vec<String>>,
-    pub attributes: Option<Vec<WorkAttribute>>,
+    pub attributes: Option<Vec<WorkAttributeEntry>>,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
     pub disambiguation: Option<String>,
@@ -635,6 +650,11 @@ pub struct Work {
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
     pub annotation: Option<String>,
+
+    /// The fields of the API that aren't modelled yet
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
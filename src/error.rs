@@ -1,18 +1,227 @@
-use thiserror::Error;
+use core::fmt;
 
-use crate::entity::api::MusicbrainzError;
+use reqwest::StatusCode;
+use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
 
-    #[error("Musicbrainz returned an unknown error")]
-    MusicbrainzError(MusicbrainzError),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("The HTTP transport failed to send the request: {0}")]
+    TransportError(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("The client configuration is invalid: {0}")]
+    InvalidConfiguration(String),
+
+    #[error("Unable to access the cassette file: {0}")]
+    CassetteIo(#[source] std::io::Error),
+
+    #[error("No response recorded in the cassette for \"{0}\"")]
+    CassetteMiss(String),
+
+    #[error("\"{0}\" is not a valid MBID")]
+    InvalidMbid(String),
+
+    /// The request is malformed, like an include that doesn't exist for the entity
+    #[error("Musicbrainz rejected the request: {0}")]
+    BadRequest(ApiError),
+
+    /// Musicbrainz doesn't consider the MBID of the request as valid
+    #[error("Musicbrainz rejected the MBID: {0}")]
+    RejectedMbid(ApiError),
+
+    /// The request exceeded the rate limit.
+    ///
+    /// It is only returned once the [`RetryPolicy`](crate::retry::RetryPolicy) gave up retrying
+    /// the request.
+    #[error("Musicbrainz rate limited the request: {0}")]
+    RateLimited(ApiError),
+
+    /// Musicbrainz is down or overloaded.
+    ///
+    /// It is only returned once the [`RetryPolicy`](crate::retry::RetryPolicy) gave up retrying
+    /// the request.
+    #[error("Musicbrainz is unavailable: {0}")]
+    ServiceUnavailable(ApiError),
+
+    /// The request needs authentication, or isn't allowed
+    #[error("The request is not authorized: {0}")]
+    Unauthorized(ApiError),
 
-    #[error("Musicbrainz returned \"Not found\" for query \"{0}\"")]
-    NotFound(String),
+    /// The response isn't JSON, like the HTML page of a proxy
+    #[error("Musicbrainz returned an unexpected content type: {0}")]
+    UnexpectedContentType(ApiError),
 
+    #[error("Musicbrainz returned an error: {0}")]
+    MusicbrainzError(ApiError),
+
+    /// The response doesn't match the entity, like an enum value added to the API
+    #[error(transparent)]
+    Deserialization(Box<DeserializationError>),
+
+    /// The entity of the request doesn't exist
+    #[error("Musicbrainz returned \"Not found\" for query \"{}\"", .0.url)]
+    NotFound(ApiError),
+
+    /// The client doesn't return it anymore: the last response of a request that is retried
+    /// in vain becomes the error matching its status, like [`RateLimited`](Error::RateLimited)
+    #[deprecated(note = "the last response of the retries becomes the error matching its status")]
     #[error("The max retry count for the request as been exeeded. You may want to check if the correct url is set, musicbrainz is online, or you aren't hitting the ratelimit.")]
     MaxRetriesExceeded(),
 }
+
+impl Error {
+    /// The details of the API error response, if the error comes from one
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::BadRequest(err)
+            | Self::RejectedMbid(err)
+            | Self::RateLimited(err)
+            | Self::ServiceUnavailable(err)
+            | Self::Unauthorized(err)
+            | Self::NotFound(err)
+            | Self::UnexpectedContentType(err)
+            | Self::MusicbrainzError(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The HTTP status of the API error response, if the error comes from one
+    pub fn status(&self) -> Option<StatusCode> {
+        self.api_error().map(|err| err.status)
+    }
+}
+
+/// An error response of the Musicbrainz API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// The HTTP status of the response
+    pub status: StatusCode,
+
+    /// The url of the request
+    pub url: String,
+
+    /// The error message given by Musicbrainz, or a description of the status if there is none
+    pub message: String,
+
+    /// The raw body of the response
+    pub body: Vec<u8>,
+}
+
+impl ApiError {
+    /// Turn the error into the variant of [`Error`] matching its status and message
+    pub(crate) fn into_error(self) -> Error {
+        let message = self.message.to_ascii_lowercase();
+
+        match self.status {
+            StatusCode::BAD_REQUEST if message.contains("mbid") => Error::RejectedMbid(self),
+            StatusCode::BAD_REQUEST => Error::BadRequest(self),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Error::Unauthorized(self),
+            StatusCode::NOT_FOUND => Error::NotFound(self),
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited(self),
+            // Musicbrainz answers with a 503 when the rate limit is hit
+            StatusCode::SERVICE_UNAVAILABLE if message.contains("rate limit") => {
+                Error::RateLimited(self)
+            }
+            StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Error::ServiceUnavailable(self),
+            _ => Error::MusicbrainzError(self),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} for \"{}\": {}", self.status, self.url, self.message)
+    }
+}
+
+/// The number of bytes of the body kept around the failure in a [`DeserializationError`]
+const EXCERPT_LEN: usize = 200;
+
+/// A response of the Musicbrainz API that couldn't be deserialized
+#[derive(Error, Debug)]
+#[error("Unable to deserialize the response of \"{url}\" at `{path}`: {source}")]
+pub struct DeserializationError {
+    /// The JSON path of the field that failed, like `media[0].tracks[3].length`
+    pub path: String,
+
+    /// The url of the request
+    pub url: String,
+
+    /// The part of the body around the failure
+    pub excerpt: String,
+
+    /// The error of the JSON deserializer
+    #[source]
+    pub source: serde_json::Error,
+}
+
+impl DeserializationError {
+    pub(crate) fn new(
+        url: &str,
+        body: &[u8],
+        error: serde_path_to_error::Error<serde_json::Error>,
+    ) -> Self {
+        let path = error.path().to_string();
+        let source = error.into_inner();
+
+        Self {
+            excerpt: excerpt(body, source.line(), source.column()),
+            path,
+            url: url.to_string(),
+            source,
+        }
+    }
+}
+
+/// Take the part of the body around the 1-based `line` and `column` given by serde_json
+fn excerpt(body: &[u8], line: usize, column: usize) -> String {
+    let body = String::from_utf8_lossy(body);
+
+    let line_start: usize = body
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let position = (line_start + column).min(body.len());
+
+    let mut start = position.saturating_sub(EXCERPT_LEN / 2);
+    while !body.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    let mut end = (start + EXCERPT_LEN).min(body.len());
+    while !body.is_char_boundary(end) {
+        end += 1;
+    }
+
+    body[start..end].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::excerpt;
+
+    #[test]
+    fn should_excerpt_the_body_around_the_failure() {
+        let body = format!("{{\n\"padding\": \"{}\",\n\"type\": 42}}", "a".repeat(300));
+        let excerpt = excerpt(body.as_bytes(), 3, 10);
+
+        assert!(excerpt.contains(r#""type": 42"#));
+        assert!(excerpt.len() <= 200);
+    }
+
+    #[test]
+    fn should_excerpt_on_char_boundaries() {
+        let body = "é".repeat(300);
+
+        assert_eq!(excerpt(body.as_bytes(), 1, 201), "é".repeat(100));
+        assert_eq!(excerpt(b"", 1, 1), "");
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::sync::lock;
+
+/// The maximum number of fields dropped from a single response before giving up
+const MAX_DROPPED_FIELDS: usize = 100;
+
+/// Deserialize the responses leniently, instead of failing on the first unexpected value.
+///
+/// The fields that can't be deserialized are replaced with `None`, or with their default value if they
+/// aren't optional. Every replaced field is reported as a [`DeserializationWarning`].
+///
+/// An element of a list has no field to replace, so the response still fails on it, unless
+/// [`LenientMode::remove_list_elements`] is set.
+///
+/// The client is strict by default. The lenient mode is shared between the clones of the client,
+/// so the warnings of all the requests end up in the same place.
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # use musicbrainz_rs::lenient::LenientMode;
+/// let lenient = LenientMode::default();
+///
+/// let mut client = MusicBrainzClient::default();
+/// client.set_lenient(lenient.clone());
+///
+/// // ... execute some queries ...
+///
+/// for warning in lenient.take_warnings() {
+///     println!("dropped `{}` of {}: {}", warning.path, warning.url, warning.message);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LenientMode {
+    warnings: Arc<Mutex<Vec<DeserializationWarning>>>,
+
+    /// Whether the list elements that can't be deserialized are removed
+    remove_list_elements: bool,
+}
+
+impl LenientMode {
+    /// Remove the elements of the lists that can't be deserialized, instead of failing.
+    ///
+    /// The whole element is lost, like a track of a release, and reported as a warning.
+    pub fn remove_list_elements(mut self) -> Self {
+        self.remove_list_elements = true;
+        self
+    }
+
+    /// Take the warnings collected since the last call
+    pub fn take_warnings(&self) -> Vec<DeserializationWarning> {
+        core::mem::take(&mut *lock(&self.warnings))
+    }
+
+    fn warn(&self, warning: DeserializationWarning) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            url = %warning.url,
+            path = %warning.path,
+            error = %warning.message,
+            "Dropped a field of the response that couldn't be deserialized"
+        );
+
+        lock(&self.warnings).push(warning);
+    }
+}
+
+/// A field of a response that was replaced by the [`LenientMode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializationWarning {
+    /// The url of the request
+    pub url: String,
+
+    /// The JSON path of the replaced field, like `media[0].tracks[3].length`
+    pub path: String,
+
+    /// Why the field couldn't be deserialized
+    pub message: String,
+}
+
+/// Deserialize the body, dropping the fields that fail one by one until it succeeds.
+///
+/// The error of the first attempt is returned if the body still can't be deserialized.
+pub(crate) fn deserialize<T>(
+    mode: &LenientMode,
+    url: &str,
+    body: &[u8],
+    first_error: serde_path_to_error::Error<serde_json::Error>,
+) -> Result<T, serde_path_to_error::Error<serde_json::Error>>
+where
+    T: DeserializeOwned,
+{
+    let Ok(mut value) = serde_json::from_slice::<Value>(body) else {
+        return Err(first_error);
+    };
+
+    let mut warnings: Vec<DeserializationWarning> = vec![];
+    let mut last_error = None;
+
+    loop {
+        let error = last_error.as_ref().unwrap_or(&first_error);
+        if warnings.len() >= MAX_DROPPED_FIELDS
+            || !drop_field(&mut value, error.path(), mode.remove_list_elements)
+        {
+            return Err(first_error);
+        }
+
+        // A field is nulled before being removed, so it may fail twice
+        let path = error.path().to_string();
+        if warnings.last().map_or(true, |warning| warning.path != path) {
+            warnings.push(DeserializationWarning {
+                url: url.to_string(),
+                path,
+                message: error.inner().to_string(),
+            });
+        }
+
+        match serde_path_to_error::deserialize(&value) {
+            Ok(entity) => {
+                for warning in warnings {
+                    mode.warn(warning);
+                }
+                return Ok(entity);
+            }
+            Err(err) => last_error = Some(err),
+        }
+    }
+}
+
+/// Replace the value at `path` with null, or remove it if it already is. Returns whether something changed.
+///
+/// The elements of the lists are only removed if `remove_list_elements` is set.
+fn drop_field(
+    value: &mut Value,
+    path: &serde_path_to_error::Path,
+    remove_list_elements: bool,
+) -> bool {
+    let segments: Vec<&Segment> = path.iter().collect();
+    let Some((last, parents)) = segments.split_last() else {
+        return false;
+    };
+
+    let Some(parent) = parents
+        .iter()
+        .try_fold(value, |value, segment| child(value, segment))
+    else {
+        return false;
+    };
+
+    match (parent, last) {
+        (Value::Object(map), Segment::Map { key }) => match map.get_mut(key) {
+            Some(Value::Null) => map.remove(key).is_some(),
+            Some(field) => {
+                *field = Value::Null;
+                true
+            }
+            None => false,
+        },
+        (Value::Array(list), Segment::Seq { index })
+            if remove_list_elements && *index < list.len() =>
+        {
+            list.remove(*index);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The value at `segment` of the path
+fn child<'a>(value: &'a mut Value, segment: &Segment) -> Option<&'a mut Value> {
+    match (value, segment) {
+        (Value::Object(map), Segment::Map { key } | Segment::Enum { variant: key }) => {
+            map.get_mut(key)
+        }
+        (Value::Array(list), Segment::Seq { index }) => list.get_mut(*index),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Script {
+        Latn,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default)]
+    struct Track {
+        title: String,
+        length: Option<u32>,
+        script: Option<Script>,
+        position: u32,
+    }
+
+    impl Default for Track {
+        fn default() -> Self {
+            Self {
+                title: String::new(),
+                length: None,
+                script: None,
+                position: 1,
+            }
+        }
+    }
+
+    fn lenient<T: DeserializeOwned>(mode: &LenientMode, body: &str) -> Result<T, String> {
+        let deserializer = &mut serde_json::Deserializer::from_str(body);
+        serde_path_to_error::deserialize(deserializer)
+            .or_else(|err| deserialize(mode, "url", body.as_bytes(), err))
+            .map_err(|err| err.path().to_string())
+    }
+
+    #[test]
+    fn should_drop_the_fields_that_fail() {
+        let mode = LenientMode::default();
+        let tracks: Vec<Track> = lenient(
+            &mode,
+            r#"[
+                {"title": "Serve the Servants", "length": 216000, "script": "Latn", "position": 1},
+                {"title": "Scentless Apprentice", "length": "3:48", "script": "Zyyy", "position": "2"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            tracks[1],
+            Track {
+                title: "Scentless Apprentice".to_string(),
+                ..Default::default()
+            }
+        );
+
+        let mut paths: Vec<String> = mode
+            .take_warnings()
+            .into_iter()
+            .map(|warning| warning.path)
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["[1].length", "[1].position", "[1].script"]);
+        assert!(mode.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn should_fail_on_the_list_elements_by_default() {
+        let mode = LenientMode::default();
+
+        assert_eq!(
+            lenient::<Vec<Script>>(&mode, r#"["Latn", "Zyyy", "Latn"]"#),
+            Err("[1]".to_string())
+        );
+        assert!(mode.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn should_remove_the_list_elements_that_fail_when_allowed() {
+        let mode = LenientMode::default().remove_list_elements();
+        let scripts: Vec<Script> = lenient(&mode, r#"["Latn", "Zyyy", "Latn"]"#).unwrap();
+
+        assert_eq!(scripts, [Script::Latn, Script::Latn]);
+        assert_eq!(mode.take_warnings()[0].path, "[1]");
+    }
+
+    #[test]
+    fn should_fail_when_nothing_can_be_dropped() {
+        let mode = LenientMode::default();
+
+        assert_eq!(lenient::<Script>(&mode, r#""Zyyy""#), Err(".".to_string()));
+        assert!(mode.take_warnings().is_empty());
+    }
+}
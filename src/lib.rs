@@ -25,12 +25,12 @@
 //!     assert_eq!(nirvana?.name, "Nirvana".to_string());
 //!     Ok(())
 //! }
-//! # #[cfg(feature = "blocking")]
+//! # #[cfg(all(feature = "blocking", not(feature = "async")))]
 //! fn main() -> Result<(), Error> {
 //!
 //!     let nirvana = Artist::fetch()
 //!         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
-//!         .execute();
+//!         .execute_blocking();
 //!
 //!     assert_eq!(nirvana?.name, "Nirvana".to_string());
 //!     Ok(())
@@ -40,11 +40,22 @@
 //! Note that you need to either directly to bring the [`Fetch`] trait in scope or use the
 //! [`prelude`] module to make the fetch method accessible.
 //!
+//! ## Blocking API
+//!
+//! With the `blocking` feature, every query also has an `execute_blocking` and an
+//! `execute_with_client_blocking` method. The `async` and `blocking` features can be enabled at
+//! the same time.
+//!
 //! [musicbrainz::prelude]: musicbrainz_rs::prelude
 //! [entity]: musicbrainz_rs::entity
 
+#[cfg(feature = "async")]
+use futures::Stream;
+use query::paging;
+use query::ApiRequest;
 use query::Query;
 use serde::de::DeserializeOwned;
+use serde::de::IgnoredAny;
 use std::marker::PhantomData;
 
 use crate::config::*;
@@ -70,17 +81,47 @@ pub mod query;
 /// Crate errors;
 pub mod error;
 
+/// The MusicBrainz identifiers
+pub mod mbid;
+
+/// Cache the responses of the API
+pub mod cache;
+
+/// Record and replay the API responses
+pub mod cassette;
+
+/// Deserialize the responses leniently, field by field
+pub mod lenient;
+
+/// Hooks around the requests of the client
+pub mod middleware;
+
+/// How the client retries failed requests
+pub mod retry;
+
+/// The helpers around the locks shared by the clients
+mod sync;
+
+/// The HTTP layer used by the client
+pub mod transport;
+
+use crate::entity::api::error_for_status;
+use crate::entity::api::RawResponse;
 use crate::entity::search::{SearchResult, Searchable};
+use crate::query::search_by_ids::SearchByIdsResult;
 use client::MusicBrainzClient;
-use client::MUSICBRAINZ_CLIENT;
 use deserialization::date_format;
 use entity::Browsable;
 use entity::BrowseResult;
 use entity::{CoverartResolution, CoverartResponse, CoverartTarget, CoverartType};
 use std::fmt::Write as _;
+use transport::HttpRequest;
+use transport::HttpResponse;
 
 /// Rexports
-pub use crate::error::Error;
+pub use crate::client::{default_client, set_default_client};
+pub use crate::error::{ApiError, DeserializationError, Error};
+pub use crate::mbid::Mbid;
 
 /// perform a lookup of an entity when you have the MBID for that entity
 ///
@@ -103,12 +144,12 @@ pub use crate::error::Error;
 /// assert_eq!(nirvana?.name, "Nirvana".to_string());
 /// #   Ok(())
 /// # }
-/// # #[cfg(feature = "blocking")]
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
 /// # fn main() -> Result<(), Error> {
 /// # use musicbrainz_rs::entity::artist::Artist;
 /// let nirvana = Artist::fetch()
 ///         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
-///         .execute();
+///         .execute_blocking();
 ///
 /// assert_eq!(nirvana?.name, "Nirvana".to_string());
 /// #   Ok(())
@@ -117,6 +158,64 @@ pub use crate::error::Error;
 #[derive(Clone, Debug)]
 pub struct FetchQuery<T>(Query<T>);
 
+/// Lookup many entities of the same type at once, knowing their MBIDs
+///
+/// # Bulk lookups
+///
+/// Every MBID is fetched with the same includes, by a separate lookup. Up to
+/// [`concurrency`](Self::concurrency) lookups are sent at the same time, and the client's rate
+/// limiter still applies to each of them. The results are yielded in completion order, along
+/// with the MBID they were requested for, and the lookups can be stopped with a
+/// [`CancelHandle`](query::fetch_many::CancelHandle).
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::prelude::*;
+/// # #[tokio::main]
+/// # #[cfg(feature = "async")]
+/// # async fn main() -> Result<(), Error> {
+/// # use futures::StreamExt;
+/// # use musicbrainz_rs::entity::recording::Recording;
+/// let mut recordings = Recording::fetch_many([
+///         "5fed738b-1e5c-4a1b-9f66-b3fd15dbc8ef",
+///         "9bdc2f4d-bc32-4e96-8bd1-4b6e6b0c4a3c",
+///     ])
+///     .with_isrcs()
+///     .execute();
+///
+/// while let Some((mbid, recording)) = recordings.next().await {
+///     println!("{mbid}: {:?}", recording.map(|recording| recording.title));
+/// }
+/// #   Ok(())
+/// # }
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
+/// # fn main() -> Result<(), Error> {
+/// # use musicbrainz_rs::entity::recording::Recording;
+/// let recordings = Recording::fetch_many([
+///         "5fed738b-1e5c-4a1b-9f66-b3fd15dbc8ef",
+///         "9bdc2f4d-bc32-4e96-8bd1-4b6e6b0c4a3c",
+///     ])
+///     .with_isrcs()
+///     .execute_blocking();
+///
+/// for (mbid, recording) in recordings {
+///     println!("{mbid}: {:?}", recording.map(|recording| recording.title));
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FetchManyQuery<T> {
+    /// The lookup sent for each MBID
+    query: FetchQuery<T>,
+
+    /// The MBIDs to fetch
+    ids: Vec<String>,
+
+    /// The maximum number of lookups sent at the same time
+    concurrency: usize,
+}
+
 /// perform a lookup of an entity's coverart when you have the MBID for that entity
 ///
 /// # Lookups
@@ -144,13 +243,13 @@ pub struct FetchQuery<T>(Query<T>);
 /// }
 /// #   Ok(())
 /// # }
-/// # #[cfg(feature = "blocking")]
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
 /// # fn main() -> Result<(), Error> {
 /// # use musicbrainz_rs::entity::release::Release;
 /// # use musicbrainz_rs::entity::CoverartResponse;
 /// let in_utero_coverart = Release::fetch_coverart()
 ///         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
-///         .execute()?;
+///         .execute_blocking()?;
 ///
 /// if let CoverartResponse::Json(coverart) = in_utero_coverart {
 ///     assert_eq!(coverart.images[0].front, true);
@@ -164,7 +263,9 @@ pub struct FetchQuery<T>(Query<T>);
 #[derive(Clone, Debug)]
 struct CoverartQuery<T> {
     path: String,
+    id: Option<String>,
     target: CoverartTarget,
+    no_cache: bool,
     phantom: PhantomData<T>,
 }
 
@@ -194,13 +295,13 @@ pub struct FetchCoverartQuery<T>(CoverartQuery<T>);
 /// assert!(!ubiktune_releases?.entities.is_empty());
 /// #   Ok(())
 /// # }
-/// # #[cfg(feature = "blocking")]
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
 /// # fn main() -> Result<(), Error> {
 /// # use musicbrainz_rs::entity::artist::Artist;
 /// # use musicbrainz_rs::entity::release::Release;
 /// let ubiktune_releases = Release::browse()
 ///         .by_label("47e718e1-7ee4-460c-b1cc-1192a841c6e5")
-///         .execute();
+///         .execute_blocking();
 ///
 /// assert!(!ubiktune_releases?.entities.is_empty());
 /// #   Ok(())
@@ -211,13 +312,13 @@ pub struct BrowseQuery<T> {
     inner: Query<T>,
 
     /// The number of results to offset the query by
-    offset: Option<u16>,
+    offset: Option<u32>,
 
     /// The number of results to query
     limit: Option<u8>,
 
-    /// The search query
-    id: String,
+    /// The entity browsed by and its MBID, like `("label", <mbid>)`
+    by: Option<(&'static str, String)>,
 }
 
 /// Search requests provide a way to search for MusicBrainz entities based on different
@@ -252,7 +353,7 @@ pub struct BrowseQuery<T> {
 ///     assert!(query_result.contains(&"Miles Davis Quintet".to_string()));
 /// #   Ok(())
 /// # }
-/// # #[cfg(feature = "blocking")]
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
 /// # fn main() -> Result<(), Error> {
 /// # use musicbrainz_rs::entity::artist::{Artist, ArtistSearchQuery};
 /// let query = ArtistSearchQuery::query_builder()
@@ -261,7 +362,7 @@ pub struct BrowseQuery<T> {
 ///         .country("US")
 ///         .build();
 ///
-///     let query_result = Artist::search(query).execute()?;
+///     let query_result = Artist::search(query).execute_blocking()?;
 ///     let query_result: Vec<String> = query_result
 ///         .entities
 ///         .iter()
@@ -278,7 +379,7 @@ pub struct SearchQuery<T> {
     inner: Query<T>,
 
     /// The number of results to offset the query by
-    offset: Option<u16>,
+    offset: Option<u32>,
 
     /// The number of results to query
     limit: Option<u8>,
@@ -287,51 +388,299 @@ pub struct SearchQuery<T> {
     search_query: String,
 }
 
+/// Search many entities of the same type at once, knowing their MBIDs
+///
+/// # Search by MBIDs
+///
+/// Instead of one lookup per MBID, the MBIDs are ORed together in a search query, so up to 100
+/// entities are resolved by a single request. The results are paged through, and the MBIDs that
+/// did not match any entity are reported in [`not_found`](query::search_by_ids::SearchByIdsResult::not_found).
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::prelude::*;
+/// # #[tokio::main]
+/// # #[cfg(feature = "async")]
+/// # async fn main() -> Result<(), Error> {
+/// # use musicbrainz_rs::entity::recording::Recording;
+/// let recordings = Recording::search_by_ids([
+///         "5fed738b-1e5c-4a1b-9f66-b3fd15dbc8ef",
+///         "9bdc2f4d-bc32-4e96-8bd1-4b6e6b0c4a3c",
+///     ])
+///     .execute()
+///     .await?;
+///
+/// for recording in recordings.entities {
+///     println!("{}: {}", recording.id, recording.title);
+/// }
+/// println!("Not found: {:?}", recordings.not_found);
+/// #   Ok(())
+/// # }
+/// # #[cfg(all(feature = "blocking", not(feature = "async")))]
+/// # fn main() -> Result<(), Error> {
+/// # use musicbrainz_rs::entity::recording::Recording;
+/// let recordings = Recording::search_by_ids([
+///         "5fed738b-1e5c-4a1b-9f66-b3fd15dbc8ef",
+///         "9bdc2f4d-bc32-4e96-8bd1-4b6e6b0c4a3c",
+///     ])
+///     .execute_blocking()?;
+///
+/// for recording in recordings.entities {
+///     println!("{}: {}", recording.id, recording.title);
+/// }
+/// println!("Not found: {:?}", recordings.not_found);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchByIdsQuery<T> {
+    /// The MBIDs to search
+    ids: Vec<String>,
+
+    /// Skip the response caches of the client
+    no_cache: bool,
+
+    result_type: PhantomData<T>,
+}
+
 impl<T> FetchQuery<T>
 where
     T: Clone,
 {
-    /// The mbid of the entity to fetch
-    pub fn id(&mut self, id: &str) -> &mut Self {
-        let _ = write!(self.0.path, "/{id}");
+    /// The mbid of the entity to fetch. Accepts both an [`Mbid`] and a string.
+    ///
+    /// Calling it again replaces the previous mbid, so the same query can be reused for many entities
+    pub fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.0.id = Some(id.as_ref().to_string());
         self
     }
 
+    /// Skip the response caches of the client. The response is neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.0.no_cache = true;
+        self
+    }
+
+    /// The url of the lookup, as sent by `client`
+    pub fn to_url(&self, client: &MusicBrainzClient) -> String {
+        self.0.create_url(client, &[])
+    }
+
+    /// The request of the query, prepared by `client` but not sent.
+    ///
+    /// The middlewares of the client are not run on it.
+    pub fn dry_run(&self, client: &MusicBrainzClient) -> HttpRequest {
+        client.build_request(&self.to_url(client))
+    }
+
     #[cfg(feature = "blocking")]
-    pub fn execute(&mut self) -> Result<T, Error>
+    pub fn execute_blocking(&self) -> Result<T, Error>
     where
         T: Fetch + DeserializeOwned,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT)
+        self.execute_with_client_blocking(default_client())
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "blocking")]
-    pub fn execute_with_client(&mut self, client: &client::MusicBrainzClient) -> Result<T, Error>
+    pub fn execute_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<T, Error>
     where
         T: Fetch + DeserializeOwned,
     {
-        client.get(&self.0.create_url(client))
+        client.get_blocking(&self.0.api_request(self.to_url(client)))
     }
 
     #[cfg(feature = "async")]
-    pub async fn execute(&mut self) -> Result<T, Error>
+    pub async fn execute(&self) -> Result<T, Error>
     where
-        T: Fetch + DeserializeOwned,
+        T: Fetch + DeserializeOwned + Send + Sync + 'static,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT).await
+        self.execute_with_client(default_client()).await
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "async")]
-    pub async fn execute_with_client(
-        &mut self,
+    pub async fn execute_with_client(&self, client: &client::MusicBrainzClient) -> Result<T, Error>
+    where
+        T: Fetch + DeserializeOwned + Send + Sync + 'static,
+    {
+        client.get(&self.0.api_request(self.to_url(client))).await
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_blocking(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client_blocking(default_client())
+    }
+
+    /// Same as [`Self::execute_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_with_client_blocking(
+        &self,
         client: &client::MusicBrainzClient,
-    ) -> Result<T, Error>
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) =
+            client.get_with_raw_blocking::<IgnoredAny>(&self.0.api_request(self.to_url(client)))?;
+        Ok(raw)
+    }
+
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_blocking(&self) -> Result<(T, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned,
+    {
+        self.execute_with_raw_with_client_blocking(default_client())
+    }
+
+    /// Same as [`Self::execute_with_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(T, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned,
+    {
+        client.get_with_raw_blocking(&self.0.api_request(self.to_url(client)))
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "async")]
+    pub async fn execute_raw(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) = client
+            .get_with_raw::<IgnoredAny>(&self.0.api_request(self.to_url(client)))
+            .await?;
+        Ok(raw)
+    }
+
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw(&self) -> Result<(T, RawResponse), Error>
     where
         T: Fetch + DeserializeOwned,
     {
-        client.get(&self.0.create_url(client)).await
+        self.execute_with_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_with_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(T, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned,
+    {
+        client
+            .get_with_raw(&self.0.api_request(self.to_url(client)))
+            .await
+    }
+}
+
+impl<T> FetchManyQuery<T>
+where
+    T: Clone,
+{
+    /// Set the maximum number of lookups sent at the same time. Defaults to 5.
+    ///
+    /// The blocking client always sends them one after the other.
+    pub fn concurrency(&mut self, concurrency: usize) -> &mut Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Skip the response caches of the client. The responses are neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.query.no_cache();
+        self
+    }
+
+    /// The lookup of a single MBID
+    fn lookup(&self, id: &str) -> FetchQuery<T> {
+        let mut query = self.query.clone();
+        query.id(id);
+        query
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&self) -> query::fetch_many::FetchManyIter<'static, T>
+    where
+        T: Fetch + DeserializeOwned + 'static,
+    {
+        self.execute_with_client_blocking(default_client())
+    }
+
+    /// Execute the lookups with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_client_blocking<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> query::fetch_many::FetchManyIter<'a, T>
+    where
+        T: Fetch + DeserializeOwned + 'a,
+    {
+        let lookups: Vec<_> = self
+            .ids
+            .iter()
+            .map(|id| (id.clone(), self.lookup(id)))
+            .collect();
+
+        query::fetch_many::FetchManyIter::new(lookups.into_iter().map(move |(id, lookup)| {
+            let result = lookup.execute_with_client_blocking(client);
+            (id, result)
+        }))
+    }
+
+    #[cfg(feature = "async")]
+    pub fn execute(&self) -> query::fetch_many::FetchManyStream<'static, T>
+    where
+        T: Fetch + DeserializeOwned + Send + Sync + 'static,
+    {
+        self.execute_with_client(default_client())
+    }
+
+    /// Execute the lookups with a specific client
+    #[cfg(feature = "async")]
+    pub fn execute_with_client<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> query::fetch_many::FetchManyStream<'a, T>
+    where
+        T: Fetch + DeserializeOwned + Send + Sync + 'static,
+    {
+        use futures::StreamExt;
+
+        let lookups: Vec<_> = self
+            .ids
+            .iter()
+            .map(|id| (id.clone(), self.lookup(id)))
+            .collect();
+
+        let results = futures::stream::iter(lookups)
+            .map(move |(id, lookup)| async move {
+                let result = lookup.execute_with_client(client).await;
+                (id, result)
+            })
+            .buffer_unordered(self.concurrency);
+
+        query::fetch_many::FetchManyStream::new(results.boxed())
     }
 }
 
@@ -339,8 +688,15 @@ impl<T> FetchCoverartQuery<T>
 where
     T: Clone + FetchCoverart,
 {
-    pub fn id(&mut self, id: &str) -> &mut Self {
-        let _ = write!(self.0.path, "/{id}");
+    /// The mbid of the entity to fetch the coverart of. Calling it again replaces the previous mbid
+    pub fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.0.id = Some(id.as_ref().to_string());
+        self
+    }
+
+    /// Skip the response caches of the client. The response is neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.0.no_cache = true;
         self
     }
 
@@ -385,66 +741,199 @@ where
     }
 
     pub fn validate(&mut self) {
-        if let Some(img_type) = &self.0.target.img_type {
-            let _ = write!(self.0.path, "/{}", img_type.as_str());
+        // Implicitly assume coverart type as front in the case when resolution is
+        // explicitly specified but coverart type is not.
+        if self.0.target.img_type.is_none() && self.0.target.img_res.is_some() {
+            self.front();
+        }
+    }
+
+    /// The url of the coverart, as sent by `client`
+    pub fn to_url(&self, client: &MusicBrainzClient) -> String {
+        let segments = self.segments();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        query::build_url(&client.coverart_archive_url, &segments, &[])
+    }
+
+    /// The request of the query, prepared by `client` but not sent.
+    ///
+    /// The middlewares of the client are not run on it.
+    pub fn dry_run(&self, client: &MusicBrainzClient) -> HttpRequest {
+        let mut request = client.build_request(&self.to_url(client));
+        request.skip_body = self.is_image();
+        request
+    }
+
+    /// Create the API request of the query, as sent by `client`
+    fn api_request(&self, client: &MusicBrainzClient) -> ApiRequest {
+        ApiRequest {
+            no_cache: self.0.no_cache,
+            ..ApiRequest::new(self.to_url(client), &self.path())
+        }
+    }
+
+    /// Whether the query targets an image, of which only the url is returned
+    fn is_image(&self) -> bool {
+        self.0.target.img_type.is_some() || self.0.target.img_res.is_some()
+    }
+
+    /// The api path of the coverart, like `release/<mbid>/front-250`
+    fn path(&self) -> String {
+        self.segments().join("/")
+    }
+
+    /// The segments of the api path of the coverart
+    fn segments(&self) -> Vec<String> {
+        let mut segments = vec![self.0.path.clone()];
+        segments.extend(self.0.id.clone());
+
+        // See `validate`
+        let img_type = match (&self.0.target.img_type, &self.0.target.img_res) {
+            (None, Some(_)) => Some(&CoverartType::Front),
+            (img_type, _) => img_type.as_ref(),
+        };
+
+        if let Some(img_type) = img_type {
+            let mut image = img_type.as_str().to_string();
             if let Some(img_res) = &self.0.target.img_res {
-                let _ = write!(self.0.path, "-{}", img_res.as_str());
+                let _ = write!(image, "-{}", img_res.as_str());
             }
-        } else if self.0.target.img_res.is_some() {
-            // Implicitly assume coverart type as front in the case when resolution is
-            // explicitly specified but coverart type is not.
-            self.front().validate();
+            segments.push(image);
         }
+
+        segments
+    }
+
+    /// Turn the response of the query into the coverart
+    fn coverart_response(&self, response: &HttpResponse) -> Result<CoverartResponse, Error> {
+        if self.is_image() {
+            return Ok(CoverartResponse::Url(response.url.clone()));
+        }
+
+        Ok(CoverartResponse::Json(serde_json::from_slice(
+            &response.body,
+        )?))
+    }
+
+    /// Send the query, and check the status of the response
+    #[cfg(feature = "blocking")]
+    fn send_blocking(&self, client: &MusicBrainzClient) -> Result<HttpResponse, Error> {
+        let request = self.api_request(client);
+        let response = client.get_coverart_blocking(&request, self.is_image())?;
+        error_for_status(&request.url, &response)?;
+        Ok(response)
     }
 
     #[cfg(feature = "blocking")]
-    pub fn execute(&mut self) -> Result<CoverartResponse, Error> {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT)
+    pub fn execute_blocking(&self) -> Result<CoverartResponse, Error> {
+        self.execute_with_client_blocking(default_client())
     }
 
     #[cfg(feature = "blocking")]
-    pub fn execute_with_client(
-        &mut self,
+    pub fn execute_with_client_blocking(
+        &self,
         client: &MusicBrainzClient,
     ) -> Result<CoverartResponse, Error> {
-        self.validate();
+        self.coverart_response(&self.send_blocking(client)?)
+    }
+
+    /// Execute the query, and return the raw response instead of the coverart.
+    ///
+    /// The body of the images isn't read, so their `json` is null.
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_blocking(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client_blocking(default_client())
+    }
 
-        let url = format!("{}/{}", client.coverart_archive_url, &self.0.path);
+    /// Same as [`Self::execute_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_with_client_blocking(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        RawResponse::new(self.send_blocking(client)?)
+    }
 
-        let response = client.send_with_retries(client.reqwest_client.get(&url))?;
-        let coverart_response = if self.0.target.img_type.is_some() {
-            let url = response.url().clone();
-            CoverartResponse::Url(url.to_string())
-        } else {
-            CoverartResponse::Json(response.json()?)
-        };
-        Ok(coverart_response)
+    /// Execute the query, and return the raw response along with the coverart.
+    ///
+    /// The body of the images isn't read, so their `json` is null.
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_blocking(&self) -> Result<(CoverartResponse, RawResponse), Error> {
+        self.execute_with_raw_with_client_blocking(default_client())
     }
 
+    /// Same as [`Self::execute_with_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_with_client_blocking(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<(CoverartResponse, RawResponse), Error> {
+        let response = self.send_blocking(client)?;
+        Ok((
+            self.coverart_response(&response)?,
+            RawResponse::new(response)?,
+        ))
+    }
+
+    /// Send the query, and check the status of the response
     #[cfg(feature = "async")]
-    pub async fn execute(&mut self) -> Result<CoverartResponse, Error> {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT).await
+    async fn send(&self, client: &MusicBrainzClient) -> Result<HttpResponse, Error> {
+        let request = self.api_request(client);
+        let response = client.get_coverart(&request, self.is_image()).await?;
+        error_for_status(&request.url, &response)?;
+        Ok(response)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn execute(&self) -> Result<CoverartResponse, Error> {
+        self.execute_with_client(default_client()).await
     }
 
     #[cfg(feature = "async")]
     pub async fn execute_with_client(
-        &mut self,
+        &self,
         client: &MusicBrainzClient,
     ) -> Result<CoverartResponse, Error> {
-        self.validate();
+        self.coverart_response(&self.send(client).await?)
+    }
 
-        let url = format!("{}/{}", client.coverart_archive_url, &self.0.path);
+    /// Execute the query, and return the raw response instead of the coverart.
+    ///
+    /// The body of the images isn't read, so their `json` is null.
+    #[cfg(feature = "async")]
+    pub async fn execute_raw(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client(default_client()).await
+    }
 
-        let response = client
-            .send_with_retries(client.reqwest_client.get(&url))
-            .await?;
-        let coverart_response = if self.0.target.img_type.is_some() {
-            let url = response.url().clone();
-            CoverartResponse::Url(url.to_string())
-        } else {
-            CoverartResponse::Json(response.json().await?)
-        };
-        Ok(coverart_response)
+    /// Same as [`Self::execute_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_raw_with_client(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        RawResponse::new(self.send(client).await?)
+    }
+
+    /// Execute the query, and return the raw response along with the coverart.
+    ///
+    /// The body of the images isn't read, so their `json` is null.
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw(&self) -> Result<(CoverartResponse, RawResponse), Error> {
+        self.execute_with_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_with_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw_with_client(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<(CoverartResponse, RawResponse), Error> {
+        let response = self.send(client).await?;
+        Ok((
+            self.coverart_response(&response)?,
+            RawResponse::new(response)?,
+        ))
     }
 }
 
@@ -453,59 +942,218 @@ where
     T: Clone,
 {
     #[cfg(feature = "blocking")]
-    pub fn execute(&mut self) -> Result<BrowseResult<T>, Error>
+    pub fn execute_blocking(&self) -> Result<BrowseResult<T>, Error>
     where
         T: Fetch + DeserializeOwned + Browsable,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT)
+        self.execute_with_client_blocking(default_client())
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "blocking")]
-    pub fn execute_with_client(
-        &mut self,
+    pub fn execute_with_client_blocking(
+        &self,
         client: &client::MusicBrainzClient,
     ) -> Result<BrowseResult<T>, Error>
     where
         T: Fetch + DeserializeOwned + Browsable,
     {
-        client.get(&self.create_url(client))
+        client.get_blocking(&self.api_request(client))
     }
 
     #[cfg(feature = "async")]
-    pub async fn execute(&mut self) -> Result<BrowseResult<T>, Error>
+    pub async fn execute(&self) -> Result<BrowseResult<T>, Error>
     where
-        T: Fetch + DeserializeOwned + Browsable,
+        T: Fetch + DeserializeOwned + Browsable + Send + Sync + 'static,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT).await
+        self.execute_with_client(default_client()).await
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "async")]
     pub async fn execute_with_client(
-        &mut self,
+        &self,
         client: &client::MusicBrainzClient,
     ) -> Result<BrowseResult<T>, Error>
+    where
+        T: Fetch + DeserializeOwned + Browsable + Send + Sync + 'static,
+    {
+        client.get(&self.api_request(client)).await
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_blocking(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client_blocking(default_client())
+    }
+
+    /// Same as [`Self::execute_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) = client.get_with_raw_blocking::<IgnoredAny>(&self.api_request(client))?;
+        Ok(raw)
+    }
+
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_blocking(&self) -> Result<(BrowseResult<T>, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned + Browsable,
+    {
+        self.execute_with_raw_with_client_blocking(default_client())
+    }
+
+    /// Same as [`Self::execute_with_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(BrowseResult<T>, RawResponse), Error>
     where
         T: Fetch + DeserializeOwned + Browsable,
     {
-        client.get(&self.create_url(client)).await
+        client.get_with_raw_blocking(&self.api_request(client))
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "async")]
+    pub async fn execute_raw(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) = client
+            .get_with_raw::<IgnoredAny>(&self.api_request(client))
+            .await?;
+        Ok(raw)
+    }
+
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw(&self) -> Result<(BrowseResult<T>, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned + Browsable,
+    {
+        self.execute_with_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_with_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(BrowseResult<T>, RawResponse), Error>
+    where
+        T: Fetch + DeserializeOwned + Browsable,
+    {
+        client.get_with_raw(&self.api_request(client)).await
+    }
+
+    /// Stream all the results of the query, from its offset.
+    ///
+    /// The pages are fetched on demand, with the maximum limit of 100 results, until all the results are returned.
+    /// The stream ends after the first error.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> impl Stream<Item = Result<T, Error>> + 'static
+    where
+        T: Fetch + DeserializeOwned + Browsable + Send + Sync + 'static,
+    {
+        self.stream_with_client(default_client())
+    }
+
+    /// Stream all the results of the query with a specific client. See [`stream`](Self::stream)
+    #[cfg(feature = "async")]
+    pub fn stream_with_client<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        T: Fetch + DeserializeOwned + Browsable + Send + Sync + 'static,
+    {
+        let query = self.clone();
+
+        paging::stream(self.offset.unwrap_or(0), move |offset| {
+            let mut query = query.clone();
+
+            async move {
+                let page = query
+                    .limit(paging::MAX_LIMIT)
+                    .offset(offset)
+                    .execute_with_client(client)
+                    .await?;
+
+                Ok((page.entities, page.count))
+            }
+        })
+    }
+
+    /// Iterate over all the results of the query, from its offset. See [`stream`](Self::stream)
+    #[cfg(feature = "blocking")]
+    pub fn iter_blocking(&self) -> impl Iterator<Item = Result<T, Error>>
+    where
+        T: Fetch + DeserializeOwned + Browsable + 'static,
+    {
+        self.iter_with_client_blocking(default_client())
+    }
+
+    /// Iterate over all the results of the query with a specific client. See [`stream`](Self::stream)
+    #[cfg(feature = "blocking")]
+    pub fn iter_with_client_blocking<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> impl Iterator<Item = Result<T, Error>> + 'a
+    where
+        T: Fetch + DeserializeOwned + Browsable + 'a,
+    {
+        let query = self.clone();
+
+        paging::iter(self.offset.unwrap_or(0), move |offset| {
+            let page = query
+                .clone()
+                .limit(paging::MAX_LIMIT)
+                .offset(offset)
+                .execute_with_client_blocking(client)?;
+
+            Ok((page.entities, page.count))
+        })
     }
 
-    fn create_url(&self, client: &MusicBrainzClient) -> String {
-        let mut url = self.inner.create_url(client);
-        url.push_str(&format!("&{}", self.id));
+    fn api_request(&self, client: &MusicBrainzClient) -> ApiRequest {
+        let mut request = self.inner.api_request(self.to_url(client));
+        request.mbid = self.by.as_ref().map(|(_, mbid)| mbid.clone());
+
+        request
+    }
 
-        if let Some(limit) = self.limit {
-            url.push_str(PARAM_LIMIT);
-            url.push_str(&limit.to_string());
+    /// The url of the query, as sent by `client`
+    pub fn to_url(&self, client: &MusicBrainzClient) -> String {
+        let limit = self.limit.map(|limit| limit.to_string());
+        let offset = self.offset.map(|offset| offset.to_string());
+
+        let mut params = vec![];
+        if let Some((entity, mbid)) = &self.by {
+            params.push((*entity, mbid.as_str()));
+        }
+        if let Some(limit) = &limit {
+            params.push((PARAM_LIMIT, limit));
         }
-        if let Some(offset) = self.offset {
-            url.push_str(PARAM_OFFSET);
-            url.push_str(&offset.to_string());
+        if let Some(offset) = &offset {
+            params.push((PARAM_OFFSET, offset));
         }
 
-        url
+        self.inner.create_url(client, &params)
     }
 
     pub fn limit(&mut self, limit: u8) -> &mut Self {
@@ -513,10 +1161,23 @@ where
         self
     }
 
-    pub fn offset(&mut self, offset: u16) -> &mut Self {
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
         self.offset = Some(offset);
         self
     }
+
+    /// Skip the response caches of the client. The response is neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.inner.no_cache = true;
+        self
+    }
+
+    /// The request of the query, prepared by `client` but not sent.
+    ///
+    /// The middlewares of the client are not run on it.
+    pub fn dry_run(&self, client: &MusicBrainzClient) -> HttpRequest {
+        client.build_request(&self.to_url(client))
+    }
 }
 
 impl<T> SearchQuery<T>
@@ -524,59 +1185,219 @@ where
     T: Search + Clone,
 {
     #[cfg(feature = "blocking")]
-    pub fn execute(&mut self) -> Result<SearchResult<T>, Error>
+    pub fn execute_blocking(&self) -> Result<SearchResult<T>, Error>
     where
         T: Search + DeserializeOwned + Searchable,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT)
+        self.execute_with_client_blocking(default_client())
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "blocking")]
-    pub fn execute_with_client(
-        &mut self,
+    pub fn execute_with_client_blocking(
+        &self,
         client: &client::MusicBrainzClient,
     ) -> Result<SearchResult<T>, Error>
     where
         T: Search + DeserializeOwned + Searchable,
     {
-        client.get(&self.create_url(client))
+        client.get_blocking(&self.inner.api_request(self.to_url(client)))
     }
 
     #[cfg(feature = "async")]
-    pub async fn execute(&mut self) -> Result<SearchResult<T>, Error>
+    pub async fn execute(&self) -> Result<SearchResult<T>, Error>
     where
-        T: Search + DeserializeOwned + Searchable,
+        T: Search + DeserializeOwned + Searchable + Send + Sync + 'static,
     {
-        self.execute_with_client(&MUSICBRAINZ_CLIENT).await
+        self.execute_with_client(default_client()).await
     }
 
     /// Execute the query with a specific client
     #[cfg(feature = "async")]
     pub async fn execute_with_client(
-        &mut self,
+        &self,
         client: &client::MusicBrainzClient,
     ) -> Result<SearchResult<T>, Error>
+    where
+        T: Search + DeserializeOwned + Searchable + Send + Sync + 'static,
+    {
+        client
+            .get(&self.inner.api_request(self.to_url(client)))
+            .await
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_blocking(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client_blocking(default_client())
+    }
+
+    /// Same as [`Self::execute_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_raw_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) = client
+            .get_with_raw_blocking::<IgnoredAny>(&self.inner.api_request(self.to_url(client)))?;
+        Ok(raw)
+    }
+
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_blocking(&self) -> Result<(SearchResult<T>, RawResponse), Error>
     where
         T: Search + DeserializeOwned + Searchable,
     {
-        client.get(&self.create_url(client)).await
+        self.execute_with_raw_with_client_blocking(default_client())
     }
 
-    fn create_url(&self, client: &MusicBrainzClient) -> String {
-        let mut url = self.inner.create_url(client);
-        url.push_str(&format!("&{}", self.search_query));
+    /// Same as [`Self::execute_with_raw_blocking`], with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_raw_with_client_blocking(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(SearchResult<T>, RawResponse), Error>
+    where
+        T: Search + DeserializeOwned + Searchable,
+    {
+        client.get_with_raw_blocking(&self.inner.api_request(self.to_url(client)))
+    }
+
+    /// Execute the query, and return the raw response instead of the deserialized one.
+    ///
+    /// The status of the response is still checked, so API errors are returned as usual.
+    #[cfg(feature = "async")]
+    pub async fn execute_raw(&self) -> Result<RawResponse, Error> {
+        self.execute_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<RawResponse, Error> {
+        let (_, raw) = client
+            .get_with_raw::<IgnoredAny>(&self.inner.api_request(self.to_url(client)))
+            .await?;
+        Ok(raw)
+    }
 
-        if let Some(limit) = self.limit {
-            url.push_str(PARAM_LIMIT);
-            url.push_str(&limit.to_string());
+    /// Execute the query, and return the raw response along with the deserialized one.
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw(&self) -> Result<(SearchResult<T>, RawResponse), Error>
+    where
+        T: Search + DeserializeOwned + Searchable,
+    {
+        self.execute_with_raw_with_client(default_client()).await
+    }
+
+    /// Same as [`Self::execute_with_raw`], with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_with_raw_with_client(
+        &self,
+        client: &client::MusicBrainzClient,
+    ) -> Result<(SearchResult<T>, RawResponse), Error>
+    where
+        T: Search + DeserializeOwned + Searchable,
+    {
+        client
+            .get_with_raw(&self.inner.api_request(self.to_url(client)))
+            .await
+    }
+
+    /// Stream all the results of the query, from its offset.
+    ///
+    /// The pages are fetched on demand, with the maximum limit of 100 results, until all the results are returned.
+    /// The stream ends after the first error.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> impl Stream<Item = Result<T, Error>> + 'static
+    where
+        T: Search + DeserializeOwned + Searchable + Send + Sync + 'static,
+    {
+        self.stream_with_client(default_client())
+    }
+
+    /// Stream all the results of the query with a specific client. See [`stream`](Self::stream)
+    #[cfg(feature = "async")]
+    pub fn stream_with_client<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        T: Search + DeserializeOwned + Searchable + Send + Sync + 'static,
+    {
+        let query = self.clone();
+
+        paging::stream(self.offset.unwrap_or(0), move |offset| {
+            let mut query = query.clone();
+
+            async move {
+                let page = query
+                    .limit(paging::MAX_LIMIT)
+                    .offset(offset)
+                    .execute_with_client(client)
+                    .await?;
+
+                Ok((page.entities, page.count))
+            }
+        })
+    }
+
+    /// Iterate over all the results of the query, from its offset. See [`stream`](Self::stream)
+    #[cfg(feature = "blocking")]
+    pub fn iter_blocking(&self) -> impl Iterator<Item = Result<T, Error>>
+    where
+        T: Search + DeserializeOwned + Searchable + 'static,
+    {
+        self.iter_with_client_blocking(default_client())
+    }
+
+    /// Iterate over all the results of the query with a specific client. See [`stream`](Self::stream)
+    #[cfg(feature = "blocking")]
+    pub fn iter_with_client_blocking<'a>(
+        &self,
+        client: &'a MusicBrainzClient,
+    ) -> impl Iterator<Item = Result<T, Error>> + 'a
+    where
+        T: Search + DeserializeOwned + Searchable + 'a,
+    {
+        let query = self.clone();
+
+        paging::iter(self.offset.unwrap_or(0), move |offset| {
+            let page = query
+                .clone()
+                .limit(paging::MAX_LIMIT)
+                .offset(offset)
+                .execute_with_client_blocking(client)?;
+
+            Ok((page.entities, page.count))
+        })
+    }
+
+    /// The url of the query, as sent by `client`
+    pub fn to_url(&self, client: &MusicBrainzClient) -> String {
+        let limit = self.limit.map(|limit| limit.to_string());
+        let offset = self.offset.map(|offset| offset.to_string());
+
+        // The query builders create a whole `query=<lucene query>` parameter
+        let query = self
+            .search_query
+            .strip_prefix("query=")
+            .unwrap_or(&self.search_query);
+
+        let mut params = vec![(PARAM_QUERY, query)];
+        if let Some(limit) = &limit {
+            params.push((PARAM_LIMIT, limit));
         }
-        if let Some(offset) = self.offset {
-            url.push_str(PARAM_OFFSET);
-            url.push_str(&offset.to_string());
+        if let Some(offset) = &offset {
+            params.push((PARAM_OFFSET, offset));
         }
 
-        url
+        self.inner.create_url(client, &params)
     }
 
     /// An integer value defining how many entries should be returned. Only values between 1 and 100 (both inclusive) are allowed. If not given, this defaults to 25.
@@ -586,10 +1407,102 @@ where
     }
 
     /// Return search results starting at a given offset. Used for paging through more than one page of results.
-    pub fn offset(&mut self, offset: u16) -> &mut Self {
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
         self.offset = Some(offset);
         self
     }
+
+    /// Skip the response caches of the client. The response is neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.inner.no_cache = true;
+        self
+    }
+
+    /// The request of the query, prepared by `client` but not sent.
+    ///
+    /// The middlewares of the client are not run on it.
+    pub fn dry_run(&self, client: &MusicBrainzClient) -> HttpRequest {
+        client.build_request(&self.to_url(client))
+    }
+}
+
+impl<T> SearchByIdsQuery<T>
+where
+    T: SearchByIds + Path + Clone,
+{
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&self) -> Result<SearchByIdsResult<T>, Error>
+    where
+        T: DeserializeOwned + Searchable + 'static,
+    {
+        self.execute_with_client_blocking(default_client())
+    }
+
+    /// Execute the searches with a specific client
+    #[cfg(feature = "blocking")]
+    pub fn execute_with_client_blocking(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<SearchByIdsResult<T>, Error>
+    where
+        T: DeserializeOwned + Searchable + 'static,
+    {
+        let mut entities = vec![];
+        for ids in query::search_by_ids::chunks(&self.ids) {
+            for entity in self.search(ids).iter_with_client_blocking(client) {
+                entities.push(entity?);
+            }
+        }
+
+        Ok(SearchByIdsResult::new(&self.ids, entities, T::mbid))
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn execute(&self) -> Result<SearchByIdsResult<T>, Error>
+    where
+        T: DeserializeOwned + Searchable + Send + Sync + 'static,
+    {
+        self.execute_with_client(default_client()).await
+    }
+
+    /// Execute the searches with a specific client
+    #[cfg(feature = "async")]
+    pub async fn execute_with_client(
+        &self,
+        client: &MusicBrainzClient,
+    ) -> Result<SearchByIdsResult<T>, Error>
+    where
+        T: DeserializeOwned + Searchable + Send + Sync + 'static,
+    {
+        use futures::TryStreamExt;
+
+        let mut entities = vec![];
+        for ids in query::search_by_ids::chunks(&self.ids) {
+            let page: Vec<T> = self
+                .search(ids)
+                .stream_with_client(client)
+                .try_collect()
+                .await?;
+            entities.extend(page);
+        }
+
+        Ok(SearchByIdsResult::new(&self.ids, entities, T::mbid))
+    }
+
+    /// Skip the response caches of the client. The responses are neither read from, nor stored in the caches
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.no_cache = true;
+        self
+    }
+
+    /// The search of a chunk of MBIDs
+    fn search(&self, ids: &[String]) -> SearchQuery<T> {
+        let mut search = T::search(T::ids_query(ids));
+        if self.no_cache {
+            search.no_cache();
+        }
+        search
+    }
 }
 
 /// Provide the entity HTTP api path, do not use this trait directly
@@ -605,10 +1518,26 @@ pub trait Fetch {
     {
         FetchQuery(Query {
             path: Self::path().to_string(),
+            id: None,
             result_type: PhantomData,
             include: vec![],
+            no_cache: false,
         })
     }
+
+    /// Lookup many entities at once. See [`FetchManyQuery`]
+    fn fetch_many<I>(ids: I) -> FetchManyQuery<Self>
+    where
+        Self: Sized + Path,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        FetchManyQuery {
+            query: Self::fetch(),
+            ids: ids.into_iter().map(|id| id.as_ref().to_string()).collect(),
+            concurrency: query::fetch_many::DEFAULT_CONCURRENCY,
+        }
+    }
 }
 
 /// Implemented by all fetchable coverart entities (see [`FetchCoverartQuery`])
@@ -619,11 +1548,13 @@ pub trait FetchCoverart {
     {
         FetchCoverartQuery(CoverartQuery {
             path: Self::path().to_string(),
+            id: None,
             phantom: PhantomData,
             target: CoverartTarget {
                 img_type: None,
                 img_res: None,
             },
+            no_cache: false,
         })
     }
 
@@ -634,11 +1565,13 @@ pub trait FetchCoverart {
     {
         FetchCoverartQuery(CoverartQuery {
             path: Self::path().to_string(),
+            id: None,
             phantom: PhantomData,
             target: CoverartTarget {
                 img_type: None,
                 img_res: None,
             },
+            no_cache: false,
         })
     }
 }
@@ -652,12 +1585,14 @@ pub trait Browse {
         BrowseQuery {
             inner: Query {
                 path: Self::path().to_string(),
+                id: None,
                 result_type: PhantomData,
                 include: vec![],
+                no_cache: false,
             },
             limit: None,
             offset: None,
-            id: String::new(),
+            by: None,
         }
     }
 }
@@ -671,8 +1606,10 @@ pub trait Search {
         SearchQuery {
             inner: Query {
                 path: Self::path().to_string(),
+                id: None,
                 result_type: PhantomData,
                 include: vec![],
+                no_cache: false,
             },
             search_query: query,
             limit: None,
@@ -680,3 +1617,25 @@ pub trait Search {
         }
     }
 }
+
+/// Implemented by all the entities that can be searched by MBIDs (see [`SearchByIdsQuery`])
+pub trait SearchByIds: Search {
+    /// The lucene query matching any of the MBIDs
+    fn ids_query(ids: &[String]) -> String;
+
+    /// The MBID of a search result
+    fn mbid(&self) -> &str;
+
+    fn search_by_ids<I>(ids: I) -> SearchByIdsQuery<Self>
+    where
+        Self: Sized + Path,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        SearchByIdsQuery {
+            ids: ids.into_iter().map(|id| id.as_ref().to_string()).collect(),
+            no_cache: false,
+            result_type: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use core::fmt;
+use core::str::FromStr;
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+
+use crate::Error;
+
+/// A [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier), validated as a UUID.
+///
+/// MBIDs are parsed from their hyphenated form (`5b11f4ce-a62d-471e-81fc-a69a8278c7da`), and
+/// stored lowercased so that two spellings of the same MBID compare equal.
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::Mbid;
+/// let nirvana: Mbid = "5b11f4ce-a62d-471e-81fc-a69a8278c7da".parse().unwrap();
+/// assert_eq!(nirvana, "5b11f4ce-a62d-471e-81fc-a69a8278c7da");
+///
+/// assert!(Mbid::try_from("Nirvana").is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Mbid(String);
+
+impl Mbid {
+    /// The hyphenated, lowercase representation of the MBID
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether `id` is a valid, hyphenated UUID
+    fn is_valid(id: &str) -> bool {
+        id.len() == 36
+            && id.char_indices().all(|(i, c)| match i {
+                8 | 13 | 18 | 23 => c == '-',
+                _ => c.is_ascii_hexdigit(),
+            })
+    }
+}
+
+impl FromStr for Mbid {
+    type Err = Error;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let id = id.trim();
+        if !Self::is_valid(id) {
+            return Err(Error::InvalidMbid(id.to_string()));
+        }
+
+        Ok(Self(id.to_ascii_lowercase()))
+    }
+}
+
+impl TryFrom<&str> for Mbid {
+    type Error = Error;
+
+    fn try_from(id: &str) -> Result<Self, Self::Error> {
+        id.parse()
+    }
+}
+
+impl TryFrom<String> for Mbid {
+    type Error = Error;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        id.parse()
+    }
+}
+
+impl From<Mbid> for String {
+    fn from(id: Mbid) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for Mbid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Mbid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for Mbid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq<str> for Mbid {
+    fn eq(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for Mbid {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Mbid {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl Serialize for Mbid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mbid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        id.parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&id), &"a hyphenated UUID"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mbid;
+
+    #[test]
+    fn should_normalize_mbids() {
+        let mbid: Mbid = " 5B11F4CE-A62D-471E-81FC-A69A8278C7DA ".parse().unwrap();
+        assert_eq!(mbid.as_str(), "5b11f4ce-a62d-471e-81fc-a69a8278c7da");
+        assert_eq!(mbid, "5B11F4CE-A62D-471E-81FC-A69A8278C7DA");
+    }
+
+    #[test]
+    fn should_reject_invalid_mbids() {
+        for id in [
+            "",
+            "5b11f4ce",
+            "5b11f4cea62d471e81fca69a8278c7da",
+            "5b11f4ce-a62d-471e-81fc-a69a8278c7dz",
+            "5b11f4ce-a62d-471e-81fc-a69a8278c7da-",
+        ] {
+            assert!(Mbid::try_from(id).is_err(), "{id}");
+        }
+    }
+
+    #[test]
+    fn should_deserialize_only_valid_mbids() {
+        let mbid: Mbid = serde_json::from_str(r#""5b11f4ce-a62d-471e-81fc-a69a8278c7da""#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&mbid).unwrap(),
+            r#""5b11f4ce-a62d-471e-81fc-a69a8278c7da""#
+        );
+
+        assert!(serde_json::from_str::<Mbid>(r#""Nirvana""#).is_err());
+    }
+
+    #[test]
+    fn should_require_the_ids_of_the_entities() {
+        use crate::entity::artist::Artist;
+
+        let artist = serde_json::from_str::<Artist>(r#"{"name": "Nirvana"}"#);
+        assert!(artist
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `id`"));
+    }
+}
@@ -0,0 +1,47 @@
+use core::fmt::Debug;
+use core::time::Duration;
+
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::Error;
+
+/// A hook called around every request the client sends, for lookups, browses, searches and cover art alike.
+///
+/// Middlewares are called for each attempt of a request, retries included, with
+/// [`before_request`](Self::before_request) in the order they were added, and
+/// [`after_response`](Self::after_response) or [`on_error`](Self::on_error) in the reverse order.
+/// Responses served by the caches are not sent, so they don't go through the middlewares.
+///
+/// ## Example
+/// ```rust
+/// # use core::time::Duration;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # use musicbrainz_rs::middleware::Middleware;
+/// # use musicbrainz_rs::transport::{HttpRequest, HttpResponse};
+/// #[derive(Debug)]
+/// struct LogRequests;
+///
+/// impl Middleware for LogRequests {
+///     fn after_response(&self, request: &HttpRequest, response: &mut HttpResponse, elapsed: Duration) {
+///         println!("{} {} in {:?}", response.status, request.url, elapsed);
+///     }
+/// }
+///
+/// let mut client = MusicBrainzClient::default();
+/// client.add_middleware(LogRequests);
+/// ```
+pub trait Middleware: Debug + Send + Sync {
+    /// Called before the request is sent. The request can be modified, for example to add headers
+    fn before_request(&self, _request: &mut HttpRequest) {}
+
+    /// Called once the response is received, with the time spent sending the request.
+    fn after_response(
+        &self,
+        _request: &HttpRequest,
+        _response: &mut HttpResponse,
+        _elapsed: Duration,
+    ) {
+    }
+
+    /// Called when the request couldn't be sent
+    fn on_error(&self, _request: &HttpRequest, _error: &Error, _elapsed: Duration) {}
+}
@@ -2,4 +2,6 @@ pub use crate::Browse;
 pub use crate::Error;
 pub use crate::Fetch;
 pub use crate::FetchCoverart;
+pub use crate::Mbid;
 pub use crate::Search;
+pub use crate::SearchByIds;
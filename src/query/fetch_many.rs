@@ -0,0 +1,150 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+#[cfg(feature = "async")]
+use core::pin::Pin;
+#[cfg(feature = "async")]
+use core::task::{Context, Poll};
+#[cfg(feature = "async")]
+use futures::stream::BoxStream;
+#[cfg(feature = "async")]
+use futures::task::AtomicWaker;
+#[cfg(feature = "async")]
+use futures::Stream;
+
+use crate::Error;
+
+/// The number of lookups sent at the same time by default
+pub(crate) const DEFAULT_CONCURRENCY: usize = 5;
+
+/// The result of a single lookup of a bulk fetch, with the MBID it was requested for
+pub type FetchManyItem<T> = (String, Result<T, Error>);
+
+/// Cancel a bulk fetch from anywhere, even from another task or thread.
+///
+/// Once cancelled, the pending lookups are dropped, no more request is sent and the results end.
+#[derive(Debug, Clone, Default)]
+pub struct CancelHandle(Arc<CancelState>);
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    #[cfg(feature = "async")]
+    waker: AtomicWaker,
+}
+
+impl CancelHandle {
+    /// Stop the bulk fetch
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        #[cfg(feature = "async")]
+        self.0.waker.wake();
+    }
+
+    /// Whether [`cancel`](Self::cancel) was called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// The results of a bulk fetch, yielded in completion order. See [`FetchManyQuery`](crate::FetchManyQuery)
+#[cfg(feature = "async")]
+pub struct FetchManyStream<'a, T> {
+    inner: Option<BoxStream<'a, FetchManyItem<T>>>,
+    cancel: CancelHandle,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> FetchManyStream<'a, T> {
+    pub(crate) fn new(inner: BoxStream<'a, FetchManyItem<T>>) -> Self {
+        Self {
+            inner: Some(inner),
+            cancel: CancelHandle::default(),
+        }
+    }
+
+    /// A handle to cancel the remaining lookups
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Stream for FetchManyStream<'_, T> {
+    type Item = FetchManyItem<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.cancel.0.waker.register(cx.waker());
+
+        if self.cancel.is_cancelled() {
+            // Drop the lookups in progress right away
+            self.inner = None;
+        }
+
+        let Some(inner) = self.inner.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let item = inner.as_mut().poll_next(cx);
+        if let Poll::Ready(None) = item {
+            self.inner = None;
+        }
+
+        item
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> core::fmt::Debug for FetchManyStream<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FetchManyStream")
+            .field("done", &self.inner.is_none())
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
+
+/// The results of a bulk fetch, looked up one after the other. See [`FetchManyQuery`](crate::FetchManyQuery)
+#[cfg(feature = "blocking")]
+pub struct FetchManyIter<'a, T> {
+    inner: Box<dyn Iterator<Item = FetchManyItem<T>> + 'a>,
+    cancel: CancelHandle,
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T> FetchManyIter<'a, T> {
+    pub(crate) fn new(inner: impl Iterator<Item = FetchManyItem<T>> + 'a) -> Self {
+        Self {
+            inner: Box::new(inner),
+            cancel: CancelHandle::default(),
+        }
+    }
+
+    /// A handle to cancel the remaining lookups
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T> Iterator for FetchManyIter<'_, T> {
+    type Item = FetchManyItem<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cancel.is_cancelled() {
+            return None;
+        }
+
+        self.inner.next()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T> core::fmt::Debug for FetchManyIter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FetchManyIter")
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
@@ -1,12 +1,18 @@
 use core::marker::PhantomData;
 
+use reqwest::Url;
+
 use crate::client::MusicBrainzClient;
 use crate::entity::Include;
 use crate::FMT_JSON;
+use crate::PARAM_FMT;
 use crate::PARAM_INC;
 
 pub mod browse;
+pub mod fetch_many;
+pub(crate) mod paging;
 pub mod relations;
+pub mod search_by_ids;
 
 /// The base element of a query
 #[derive(Clone, Debug)]
@@ -14,38 +20,127 @@ pub(crate) struct Query<T> {
     /// The path of the api to query
     pub(crate) path: String,
 
+    /// The MBID of the looked up entity
+    pub(crate) id: Option<String>,
+
     /// The includes added to the query
     pub(crate) include: Vec<Include>,
 
     /// The resulting type of the query
     pub(crate) result_type: PhantomData<T>,
+
+    /// Skip the response cache of the client
+    pub(crate) no_cache: bool,
+}
+
+/// A request to the MusicBrainz API, created from a query
+#[derive(Clone, Debug)]
+pub(crate) struct ApiRequest {
+    /// The full url of the request
+    pub(crate) url: String,
+
+    /// The api path of the queried entity, like `artist`
+    pub(crate) entity: String,
+
+    /// The MBID of the fetched entity, or of the entity browsed by
+    pub(crate) mbid: Option<String>,
+
+    /// The includes of the query, joined by `+`
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub(crate) includes: String,
+
+    /// Skip the response cache of the client
+    pub(crate) no_cache: bool,
+}
+
+impl ApiRequest {
+    /// Create the request for an api path, like `artist/<mbid>`
+    pub(crate) fn new(url: String, path: &str) -> Self {
+        let mut segments = path.split('/');
+
+        Self {
+            url,
+            entity: segments.next().unwrap_or_default().to_string(),
+            mbid: segments.next().map(ToString::to_string),
+            includes: String::new(),
+            no_cache: false,
+        }
+    }
 }
 
 impl<T> Query<T> {
-    /// Add an include parameter to the query
+    /// Add an include parameter to the query. Includes are kept sorted, so that the url does not
+    /// depend on the order they were added in
     pub(crate) fn include(&mut self, include: Include) -> &mut Self {
-        self.include.push(include);
+        if let Err(index) = self
+            .include
+            .binary_search_by(|inc| inc.as_str().cmp(include.as_str()))
+        {
+            self.include.insert(index, include);
+        }
         self
     }
 
-    /// Create the full url path of the query
-    pub(crate) fn create_url(&self, client: &MusicBrainzClient) -> String {
-        let mut url = format!("{}/{}{}", client.musicbrainz_url, self.path, FMT_JSON);
+    /// The api path of the query, with the MBID of the looked up entity
+    pub(crate) fn full_path(&self) -> String {
+        match &self.id {
+            Some(id) => format!("{}/{id}", self.path),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Create the API request for the url of the query
+    pub(crate) fn api_request(&self, url: String) -> ApiRequest {
+        let includes: Vec<&str> = self.include.iter().map(Include::as_str).collect();
 
-        // If we don't have includes, let's return early
-        if self.include.is_empty() {
-            return url;
+        ApiRequest {
+            includes: includes.join("+"),
+            no_cache: self.no_cache,
+            ..ApiRequest::new(url, &self.full_path())
         }
+    }
+
+    /// Create the full url of the query, with the additional parameters
+    pub(crate) fn create_url(&self, client: &MusicBrainzClient, params: &[(&str, &str)]) -> String {
+        let includes: Vec<&str> = self.include.iter().map(Include::as_str).collect();
+        let includes = includes.join(" ");
 
-        url.push_str(PARAM_INC);
+        let mut segments = vec![self.path.as_str()];
+        segments.extend(self.id.as_deref());
 
-        for inc in &self.include {
-            url.push_str(inc.as_str());
-            if Some(inc) != self.include.last() {
-                url.push('+');
-            }
+        let mut query = vec![(PARAM_FMT, FMT_JSON)];
+        if !includes.is_empty() {
+            query.push((PARAM_INC, &includes));
         }
+        query.extend_from_slice(params);
+
+        build_url(&client.musicbrainz_url, &segments, &query)
+    }
+}
+
+/// Build the url of an api path on `base`, percent-encoding every path segment and parameter
+pub(crate) fn build_url(base: &str, segments: &[&str], params: &[(&str, &str)]) -> String {
+    // The urls of the client are checked by its builder, but they can still be changed afterwards.
+    // In that case the base is kept as is, and only the encoded path and query are appended to it.
+    let (mut url, raw_base) = match Url::parse(base) {
+        Ok(url) if !url.cannot_be_a_base() => (url, None),
+        _ => (
+            Url::parse("http://localhost").expect("The placeholder url is valid"),
+            Some(base.trim_end_matches('/')),
+        ),
+    };
+
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.pop_if_empty().extend(segments);
+    }
+
+    if !params.is_empty() {
+        url.query_pairs_mut().extend_pairs(params);
+    }
 
-        url
+    match (raw_base, url.query()) {
+        (None, _) => url.into(),
+        (Some(base), None) => format!("{base}{}", url.path()),
+        (Some(base), Some(query)) => format!("{base}{}?{query}", url.path()),
     }
 }
@@ -0,0 +1,75 @@
+#[cfg(feature = "blocking")]
+use std::collections::VecDeque;
+
+#[cfg(feature = "async")]
+use core::future::Future;
+#[cfg(feature = "async")]
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::Error;
+
+/// The maximum number of results the API returns in a page
+pub(crate) const MAX_LIMIT: u8 = 100;
+
+/// A page of results, with the total number of results
+pub(crate) type Page<T> = (Vec<T>, i32);
+
+/// The offset of the page after the one fetched at `offset`, or `None` if it was the last one
+fn next_offset<T>(offset: u32, page: &Page<T>) -> Option<u32> {
+    let (entities, count) = page;
+    let next = offset.checked_add(u32::try_from(entities.len()).ok()?)?;
+
+    (!entities.is_empty() && i64::from(next) < i64::from(*count)).then_some(next)
+}
+
+/// Stream all the results from `start`, fetching the pages on demand. The stream ends after the first error
+#[cfg(feature = "async")]
+pub(crate) fn stream<'a, T, F, Fut>(
+    start: u32,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    F: Fn(u32) -> Fut + 'a,
+    Fut: Future<Output = Result<Page<T>, Error>> + 'a,
+{
+    stream::try_unfold(Some(start), move |offset| {
+        let page = offset.map(&fetch_page);
+
+        async move {
+            let (Some(offset), Some(page)) = (offset, page) else {
+                return Ok::<_, Error>(None);
+            };
+
+            let page = page.await?;
+            let next = next_offset(offset, &page);
+            Ok(Some((stream::iter(page.0.into_iter().map(Ok)), next)))
+        }
+    })
+    .try_flatten()
+}
+
+/// Iterate over all the results from `start`, fetching the pages on demand. The iterator ends after the first error
+#[cfg(feature = "blocking")]
+pub(crate) fn iter<T>(
+    start: u32,
+    mut fetch_page: impl FnMut(u32) -> Result<Page<T>, Error>,
+) -> impl Iterator<Item = Result<T, Error>> {
+    let mut buffer = VecDeque::new();
+    let mut next = Some(start);
+
+    std::iter::from_fn(move || loop {
+        if let Some(entity) = buffer.pop_front() {
+            return Some(Ok(entity));
+        }
+
+        let offset = next.take()?;
+        match fetch_page(offset) {
+            Ok(page) => {
+                next = next_offset(offset, &page);
+                buffer.extend(page.0);
+            }
+            Err(err) => return Some(Err(err)),
+        }
+    })
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+/// The maximum number of MBIDs sent in a single search request
+pub(crate) const MAX_IDS: usize = 100;
+
+/// The results of a search by MBIDs. See [`SearchByIdsQuery`](crate::SearchByIdsQuery)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SearchByIdsResult<T> {
+    /// The entities found, in the order of the search results
+    pub entities: Vec<T>,
+
+    /// The MBIDs that did not match any entity, in the order they were given
+    pub not_found: Vec<String>,
+}
+
+impl<T> SearchByIdsResult<T> {
+    /// Collect the entities found for `ids`, and report the missing ones
+    pub(crate) fn new(ids: &[String], entities: Vec<T>, mbid: impl Fn(&T) -> &str) -> Self {
+        // MBIDs are case insensitive, but Musicbrainz always returns them in lowercase
+        let found: HashSet<String> = entities
+            .iter()
+            .map(|entity| mbid(entity).to_ascii_lowercase())
+            .collect();
+
+        let not_found = ids
+            .iter()
+            .filter(|id| !found.contains(&id.to_ascii_lowercase()))
+            .cloned()
+            .collect();
+
+        Self {
+            entities,
+            not_found,
+        }
+    }
+}
+
+/// Split the MBIDs in chunks that fit in a single search request
+pub(crate) fn chunks(ids: &[String]) -> impl Iterator<Item = &[String]> {
+    ids.chunks(MAX_IDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_the_ids_case_insensitively() {
+        let ids = [
+            "5B11F4CE-A62D-471E-81FC-A69A8278C7DA".to_string(),
+            "a74b1b7f-71a5-4011-9441-d0b5e4122711".to_string(),
+        ];
+        let entities = vec!["5b11f4ce-a62d-471e-81fc-a69a8278c7da".to_string()];
+
+        let result = SearchByIdsResult::new(&ids, entities, String::as_str);
+
+        assert_eq!(result.not_found, [ids[1].clone()]);
+    }
+}
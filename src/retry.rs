@@ -0,0 +1,334 @@
+use core::time::Duration;
+use std::io;
+
+use chrono::DateTime;
+use chrono::Utc;
+use reqwest::header::HeaderMap;
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+
+use crate::transport::HttpResponse;
+use crate::Error;
+
+/// The maximum number of retries of the default policy
+pub(crate) const DEFAULT_MAX_RETRIES: u32 = 10;
+
+/// Decide when and how often the client retries a failed request.
+///
+/// A request is retried when the server answers with one of the [`retry_statuses`](Self::retry_statuses),
+/// or when the connection fails or times out. The delay between two attempts grows exponentially
+/// and is randomized with some jitter, unless the server sends a `Retry-After` header.
+///
+/// ## Example
+/// ```rust
+/// # use core::time::Duration;
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # use musicbrainz_rs::retry::RetryPolicy;
+/// let mut client = MusicBrainzClient::default();
+/// client.retry_policy = RetryPolicy {
+///     max_retries: 3,
+///     max_elapsed_time: Some(Duration::from_secs(30)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the first attempt
+    pub max_retries: u32,
+
+    /// The maximum total time spent on a request, waits included. No retry is attempted if it would end after this limit.
+    pub max_elapsed_time: Option<Duration>,
+
+    /// The delay before the first retry
+    pub initial_backoff: Duration,
+
+    /// The maximum delay between two attempts
+    pub max_backoff: Duration,
+
+    /// The factor applied to the delay after each retry
+    pub multiplier: f64,
+
+    /// The fraction of the delay that is randomized, between `0.0` (no jitter) and `1.0`
+    pub jitter: f64,
+
+    /// The HTTP status codes that are worth retrying
+    pub retry_statuses: Vec<u16>,
+
+    /// Whether timeouts and connection errors are retried
+    pub retry_network_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_elapsed_time: Some(Duration::from_secs(5 * 60)),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.5,
+            retry_statuses: vec![429, 500, 502, 503, 504],
+            retry_network_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn no_retry() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the server response is worth retrying
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status.as_u16())
+    }
+
+    /// Whether the error is a timeout or a connection failure worth retrying
+    pub fn is_retryable_error(&self, error: &Error) -> bool {
+        if !self.retry_network_errors {
+            return false;
+        }
+
+        match error {
+            Error::ReqwestError(err) => {
+                err.is_timeout() || err.is_connect() || is_connection_reset(err)
+            }
+            Error::TransportError(err) => is_connection_reset(err.as_ref()),
+            _ => false,
+        }
+    }
+
+    /// The delay to wait before the `retries`-th retry, without jitter
+    fn exponential_backoff(&self, retries: u32) -> Duration {
+        let factor = self.multiplier.powi(retries.min(i32::MAX as u32) as i32);
+        let backoff = self.initial_backoff.as_secs_f64() * factor;
+
+        if !backoff.is_finite() || backoff >= self.max_backoff.as_secs_f64() {
+            return self.max_backoff;
+        }
+
+        Duration::from_secs_f64(backoff)
+    }
+
+    /// The delay to wait before the `retries`-th retry, with jitter
+    pub fn backoff(&self, retries: u32) -> Duration {
+        let backoff = self.exponential_backoff(retries);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+
+        backoff.mul_f64(1.0 - jitter * fastrand::f64())
+    }
+
+    /// Return how long to wait before retrying the request, or `None` if it shouldn't be retried.
+    ///
+    /// `retries` is the number of retries already done and `elapsed` the time spent on the request so far.
+    pub(crate) fn retry_delay(
+        &self,
+        result: &Result<HttpResponse, Error>,
+        retries: u32,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        let delay = match result {
+            Ok(response) if self.is_retryable_status(response.status) => {
+                retry_after(&response.headers).unwrap_or_else(|| self.backoff(retries))
+            }
+            Err(err) if self.is_retryable_error(err) => self.backoff(retries),
+            _ => return None,
+        };
+
+        if retries >= self.max_retries {
+            return None;
+        }
+
+        if self
+            .max_elapsed_time
+            .is_some_and(|max_elapsed| elapsed + delay > max_elapsed)
+        {
+            return None;
+        }
+
+        Some(delay)
+    }
+}
+
+/// Parse a `Retry-After` header, given either in seconds or as an HTTP date
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Look for a reset or aborted connection in the source chain of the error
+fn is_connection_reset(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(error);
+
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<io::Error>() {
+            if matches!(
+                io_err.kind(),
+                io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+
+        source = err.source();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn response(status: u16, retry_after: Option<&str>) -> Result<HttpResponse, Error> {
+        let mut headers = HeaderMap::new();
+        if let Some(retry_after) = retry_after {
+            headers.insert(RETRY_AFTER, HeaderValue::from_str(retry_after).unwrap());
+        }
+
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            url: String::new(),
+            headers,
+            body: vec![],
+        })
+    }
+
+    #[test]
+    fn should_parse_retry_after_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn should_parse_retry_after_http_date() {
+        let date = (Utc::now() + chrono::Duration::seconds(120)).to_rfc2822();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(&date).unwrap());
+
+        let delay = retry_after(&headers).unwrap();
+        assert!(delay > Duration::from_secs(110) && delay <= Duration::from_secs(120));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn should_grow_backoff_up_to_max() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(10), Duration::from_secs(30));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn should_apply_jitter() {
+        let policy = RetryPolicy::default();
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(1);
+            assert!(backoff > Duration::from_millis(500) && backoff <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn should_only_retry_retryable_responses() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy
+            .retry_delay(&response(503, None), 0, Duration::ZERO)
+            .is_some());
+        assert!(policy
+            .retry_delay(&response(429, None), 0, Duration::ZERO)
+            .is_some());
+        assert!(policy
+            .retry_delay(&response(200, None), 0, Duration::ZERO)
+            .is_none());
+        assert!(policy
+            .retry_delay(&response(404, None), 0, Duration::ZERO)
+            .is_none());
+        assert!(policy
+            .retry_delay(
+                &Err(Error::InvalidMbid("42".to_string())),
+                0,
+                Duration::ZERO
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn should_honour_retry_after() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.retry_delay(&response(503, Some("3")), 0, Duration::ZERO),
+            Some(Duration::from_secs(3))
+        );
+    }
+
+    #[test]
+    fn should_stop_retrying_at_limits() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            max_elapsed_time: Some(Duration::from_secs(10)),
+            ..Default::default()
+        };
+
+        assert!(policy
+            .retry_delay(&response(503, Some("1")), 2, Duration::ZERO)
+            .is_none());
+        assert!(policy
+            .retry_delay(&response(503, Some("5")), 0, Duration::from_secs(6))
+            .is_none());
+        assert!(policy
+            .retry_delay(&response(503, Some("5")), 1, Duration::from_secs(4))
+            .is_some());
+    }
+
+    #[test]
+    fn should_retry_connection_resets() {
+        let policy = RetryPolicy::default();
+        let reset =
+            Error::TransportError(Box::new(io::Error::from(io::ErrorKind::ConnectionReset)));
+        let other =
+            Error::TransportError(Box::new(io::Error::from(io::ErrorKind::PermissionDenied)));
+
+        assert!(policy.is_retryable_error(&reset));
+        assert!(!policy.is_retryable_error(&other));
+        assert!(!RetryPolicy {
+            retry_network_errors: false,
+            ..Default::default()
+        }
+        .is_retryable_error(&reset));
+    }
+}
@@ -0,0 +1,11 @@
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+/// Lock the mutex, even if a thread panicked while holding it.
+///
+/// The state shared by the clients is only changed through single calls that leave it consistent,
+/// so a poisoned lock can still be used.
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
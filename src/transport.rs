@@ -0,0 +1,203 @@
+use core::fmt::Debug;
+#[cfg(feature = "async")]
+use core::future::Future;
+#[cfg(feature = "async")]
+use core::pin::Pin;
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use reqwest::StatusCode;
+
+use crate::Error;
+
+/// A future returned by an async [`Transport`]
+#[cfg(feature = "async")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An HTTP request prepared by the [`MusicBrainzClient`](crate::client::MusicBrainzClient)
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+
+    /// The body of the response isn't needed, like for the cover art images of which only the
+    /// url is kept. Transports can leave it unread and return an empty body
+    pub skip_body: bool,
+}
+
+impl HttpRequest {
+    pub fn get(url: &str) -> Self {
+        Self {
+            method: Method::GET,
+            url: url.to_string(),
+            headers: HeaderMap::new(),
+            skip_body: false,
+        }
+    }
+}
+
+/// A fully read HTTP response, as returned by a transport
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+
+    /// The url the response comes from. This may differ from the request url if redirects were followed
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// The HTTP stack used by the async client to send its requests.
+///
+/// The client takes care of the user agent, rate limiting and retries.
+/// A transport only has to send the request it is given and read back the whole response.
+/// Non success status codes must be returned as a response, not as an error.
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs::client::MusicBrainzClient;
+/// # use musicbrainz_rs::transport::*;
+/// # use musicbrainz_rs::Error;
+/// #[derive(Debug)]
+/// struct NotFoundTransport;
+///
+/// impl Transport for NotFoundTransport {
+///     fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+///         Box::pin(async move {
+///             Ok(HttpResponse {
+///                 status: reqwest::StatusCode::NOT_FOUND,
+///                 url: request.url,
+///                 headers: Default::default(),
+///                 body: br#"{"error": "Not Found", "help": ""}"#.to_vec(),
+///             })
+///         })
+///     }
+/// }
+///
+/// let mut client = MusicBrainzClient::default();
+/// client.set_transport(NotFoundTransport);
+/// ```
+#[cfg(feature = "async")]
+pub trait Transport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>>;
+}
+
+/// The HTTP stack used by the blocking client to send its requests.
+///
+/// See [`Transport`] for the async version.
+#[cfg(feature = "blocking")]
+pub trait BlockingTransport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+/// The default async transport, backed by [`reqwest::Client`]
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        let client = reqwest::Client::builder()
+            // see : https://github.com/hyperium/hyper/issues/2136
+            .pool_max_idle_per_host(0)
+            .build()
+            .expect("Unable to build the default reqwest client");
+
+        Self::new(client)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .request(request.method, &request.url)
+                .headers(request.headers)
+                .send()
+                .await?;
+
+            let status = response.status();
+            let url = response.url().to_string();
+            let headers = response.headers().clone();
+            let body = if request.skip_body {
+                Vec::new()
+            } else {
+                response.bytes().await?.to_vec()
+            };
+
+            Ok(HttpResponse {
+                status,
+                url,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// The default blocking transport, backed by [`reqwest::blocking::Client`]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
+pub struct BlockingReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingReqwestTransport {
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Default for BlockingReqwestTransport {
+    fn default() -> Self {
+        let client = reqwest::blocking::Client::builder()
+            // see : https://github.com/hyperium/hyper/issues/2136
+            .pool_max_idle_per_host(0)
+            .build()
+            .expect("Unable to build the default reqwest client");
+
+        Self::new(client)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for BlockingReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let response = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers)
+            .send()?;
+
+        let status = response.status();
+        let url = response.url().to_string();
+        let headers = response.headers().clone();
+        let body = if request.skip_body {
+            Vec::new()
+        } else {
+            response.bytes()?.to_vec()
+        };
+
+        Ok(HttpResponse {
+            status,
+            url,
+            headers,
+            body,
+        })
+    }
+}
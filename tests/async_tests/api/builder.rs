@@ -0,0 +1,73 @@
+use core::time::Duration;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::Error;
+use reqwest::header::USER_AGENT;
+
+use crate::fake_transport::*;
+
+#[tokio::test]
+async fn should_fetch_with_built_client() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        "https://test.musicbrainz.org/ws/2/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json",
+        200,
+        NIRVANA_JSON,
+    );
+
+    let mut client = MusicBrainzClient::builder()
+        .user_agent("musicbrainz_rs_testing/0.9")
+        .musicbrainz_url("https://test.musicbrainz.org/ws/2/")
+        .connect_timeout(Duration::from_secs(5))
+        .read_timeout(Duration::from_secs(30))
+        .proxy("http://localhost:3128")
+        .pool_max_idle_per_host(4)
+        .build()
+        .unwrap();
+    client.set_transport(transport.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].headers.get(USER_AGENT).unwrap(),
+        "musicbrainz_rs_testing/0.9"
+    );
+}
+
+#[test]
+fn should_report_invalid_configuration() {
+    assert!(matches!(
+        MusicBrainzClient::builder()
+            .user_agent("invalid\nuser agent")
+            .build(),
+        Err(Error::InvalidConfiguration(_))
+    ));
+
+    assert!(matches!(
+        MusicBrainzClient::builder()
+            .musicbrainz_url("not an url")
+            .build(),
+        Err(Error::InvalidConfiguration(_))
+    ));
+
+    assert!(MusicBrainzClient::builder()
+        .proxy("not a proxy url")
+        .build()
+        .is_err());
+
+    #[cfg(any(feature = "default_tls", feature = "rustls"))]
+    assert!(MusicBrainzClient::builder()
+        .add_root_certificate(b"not a certificate")
+        .build()
+        .is_err());
+}
@@ -0,0 +1,42 @@
+use core::num::NonZeroUsize;
+
+use musicbrainz_rs::cache::ResponseCache;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[tokio::test]
+async fn should_answer_repeated_lookups_from_cache() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::builder()
+        .response_cache(ResponseCache::new(NonZeroUsize::new(10).unwrap()))
+        .build()
+        .unwrap();
+    client.set_transport(transport.clone());
+
+    for _ in 0..3 {
+        let nirvana = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(nirvana.name, "Nirvana");
+    }
+
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .no_cache()
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    let cache = client.response_cache.as_ref().unwrap();
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.misses(), 1);
+    assert_eq!(transport.requests().len(), 2);
+}
@@ -0,0 +1,49 @@
+use musicbrainz_rs::cassette::Cassette;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[tokio::test]
+async fn should_replay_recorded_responses() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nirvana.json");
+
+    // Record
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+    client.set_cassette(Cassette::record(&path));
+
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    // Replay, without any response available from the transport
+    let offline = FakeTransport::default();
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(offline.clone());
+    client.set_cassette(Cassette::replay(&path).unwrap());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert!(offline.requests().is_empty());
+
+    let missing = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7db")
+        .execute_with_client(&client)
+        .await;
+
+    assert!(matches!(missing, Err(Error::CassetteMiss(_))));
+}
@@ -0,0 +1,113 @@
+use core::time::Duration;
+
+use musicbrainz_rs::cache::DiskCache;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
+
+use crate::fake_transport::*;
+
+#[tokio::test]
+async fn should_revalidate_stale_responses() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.insert(ETAG, HeaderValue::from_static("\"nirvana-v1\""));
+
+    let transport = FakeTransport::default();
+    transport
+        .respond_with_headers(NIRVANA_URL, 200, headers, NIRVANA_JSON)
+        .respond(NIRVANA_URL, 304, "");
+
+    let mut client = MusicBrainzClient::builder()
+        .disk_cache(DiskCache::new(dir.path()).with_max_age(Duration::ZERO))
+        .build()
+        .unwrap();
+    client.set_transport(transport.clone());
+
+    for _ in 0..2 {
+        let nirvana = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(nirvana.name, "Nirvana");
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].headers.get(IF_NONE_MATCH).is_none());
+    assert_eq!(
+        requests[1].headers.get(IF_NONE_MATCH).unwrap(),
+        "\"nirvana-v1\""
+    );
+
+    let cache = client.disk_cache.as_ref().unwrap();
+    assert_eq!(cache.hits(), 1);
+    assert_eq!(cache.misses(), 1);
+}
+
+#[tokio::test]
+async fn should_reuse_fresh_responses_across_clients() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    let transport = FakeTransport::default();
+    transport.respond_with_headers(NIRVANA_URL, 200, headers, NIRVANA_JSON);
+
+    for _ in 0..2 {
+        let mut client = MusicBrainzClient::builder()
+            .disk_cache(DiskCache::new(dir.path()))
+            .build()
+            .unwrap();
+        client.set_transport(transport.clone());
+
+        let nirvana = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(nirvana.name, "Nirvana");
+    }
+
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn should_skip_the_cache_for_coverart_without_cache() {
+    use musicbrainz_rs::entity::release::Release;
+
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    let mut client = MusicBrainzClient::builder()
+        .disk_cache(DiskCache::new(dir.path()))
+        .build()
+        .unwrap();
+    let transport = FakeTransport::default();
+    client.set_transport(transport.clone());
+
+    let mut query = Release::fetch_coverart();
+    query.id("76df3287-6cda-33eb-8e9a-044b5e15ffdd");
+    transport.respond_with_headers(
+        &query.to_url(&client),
+        200,
+        headers,
+        r#"{"images": [], "release": ""}"#,
+    );
+
+    query.execute_with_client(&client).await.unwrap();
+    query.execute_with_client(&client).await.unwrap();
+    assert_eq!(transport.requests().len(), 1);
+
+    query.no_cache().execute_with_client(&client).await.unwrap();
+    assert_eq!(transport.requests().len(), 2);
+}
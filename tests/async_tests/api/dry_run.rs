@@ -0,0 +1,102 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::entity::release::{Release, ReleaseSearchQuery};
+use musicbrainz_rs::prelude::*;
+use reqwest::header::USER_AGENT;
+use reqwest::Method;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_preview_the_url_of_every_query() {
+    let client = MusicBrainzClient::builder()
+        .musicbrainz_url("https://test.musicbrainz.org/ws/2")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .with_aliases()
+            .to_url(&client),
+        "https://test.musicbrainz.org/ws/2/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json&inc=aliases"
+    );
+    assert_eq!(
+        Release::browse()
+            .by_label("47e718e1-7ee4-460c-b1cc-1192a841c6e5")
+            .limit(10)
+            .to_url(&client),
+        "https://test.musicbrainz.org/ws/2/release?fmt=json&label=47e718e1-7ee4-460c-b1cc-1192a841c6e5&limit=10"
+    );
+    assert_eq!(
+        Artist::search(ArtistSearchQuery::query_builder().artist("Nirvana").build())
+            .to_url(&client),
+        "https://test.musicbrainz.org/ws/2/artist?fmt=json&query=artist%3ANirvana"
+    );
+    assert_eq!(
+        Release::fetch_coverart()
+            .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
+            .res_500()
+            .to_url(&client),
+        "http://coverartarchive.org/release/76df3287-6cda-33eb-8e9a-044b5e15ffdd/front-500"
+    );
+}
+
+#[test]
+fn should_prepare_requests_without_sending_them() {
+    let transport = FakeTransport::default();
+
+    let mut client = MusicBrainzClient::default();
+    client.set_user_agent("musicbrainz_rs_testing/0.9").unwrap();
+    client.set_transport(transport.clone());
+
+    let request = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .dry_run(&client);
+
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.url, NIRVANA_URL);
+    assert_eq!(
+        request.headers.get(USER_AGENT).unwrap(),
+        "musicbrainz_rs_testing/0.9"
+    );
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn should_encode_every_url_component() {
+    let client = MusicBrainzClient::default();
+
+    assert_eq!(
+        Artist::search(
+            ArtistSearchQuery::query_builder()
+                .artist("Simon & Garfunkel")
+                .or()
+                .artist("AC/DC")
+                .build()
+        )
+        .to_url(&client),
+        "http://musicbrainz.org/ws/2/artist?fmt=json&query=artist%3A%22Simon+%26+Garfunkel%22+OR+artist%3AAC%2FDC"
+    );
+    assert_eq!(
+        Release::search(
+            ReleaseSearchQuery::query_builder()
+                .release("#1 Hits")
+                .build()
+        )
+        .to_url(&client),
+        "http://musicbrainz.org/ws/2/release?fmt=json&query=release%3A%22%231+Hits%22"
+    );
+    assert_eq!(
+        Artist::search(ArtistSearchQuery::query_builder().artist("Björk").build()).to_url(&client),
+        "http://musicbrainz.org/ws/2/artist?fmt=json&query=artist%3ABj%C3%B6rk"
+    );
+    assert_eq!(
+        Artist::fetch().id("AC/DC #1?").to_url(&client),
+        "http://musicbrainz.org/ws/2/artist/AC%2FDC%20%231%3F?fmt=json"
+    );
+    assert_eq!(
+        Release::browse().by_artist("a&b=c").to_url(&client),
+        "http://musicbrainz.org/ws/2/release?fmt=json&artist=a%26b%3Dc"
+    );
+}
@@ -0,0 +1,126 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::retry::RetryPolicy;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+
+use crate::fake_transport::*;
+
+fn client_without_retries(transport: &FakeTransport) -> MusicBrainzClient {
+    let mut client = client_with(transport);
+    client.retry_policy = RetryPolicy::no_retry();
+    client
+}
+
+async fn fetch_nirvana(transport: &FakeTransport) -> Result<Artist, Error> {
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client_without_retries(transport))
+        .await
+}
+
+#[tokio::test]
+async fn should_return_typed_api_errors() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        NIRVANA_URL,
+        400,
+        r#"{"error": "Invalid mbid.", "help": "For usage, please see: https://musicbrainz.org/development/mmd"}"#,
+    );
+
+    let err = fetch_nirvana(&transport).await.unwrap_err();
+    assert!(matches!(err, Error::RejectedMbid(_)));
+
+    let api_error = err.api_error().unwrap();
+    assert_eq!(api_error.status, StatusCode::BAD_REQUEST);
+    assert_eq!(api_error.url, NIRVANA_URL);
+    assert_eq!(api_error.message, "Invalid mbid.");
+    assert!(api_error.body.starts_with(br#"{"error": "Invalid mbid.""#));
+}
+
+#[tokio::test]
+async fn should_tell_the_api_errors_apart() {
+    let cases = [
+        (
+            400,
+            r#"{"error": "foo is not a valid inc parameter for the artist resource."}"#,
+            "BadRequest",
+        ),
+        (401, r#"{"error": "Unauthorized"}"#, "Unauthorized"),
+        (
+            503,
+            r#"{"error": "Your requests are exceeding the allowable rate limit."}"#,
+            "RateLimited",
+        ),
+        (429, "", "RateLimited"),
+        (502, "<html>Bad Gateway</html>", "ServiceUnavailable"),
+        (500, "", "MusicbrainzError"),
+    ];
+
+    for (status, body, variant) in cases {
+        let transport = FakeTransport::default();
+        transport.respond(NIRVANA_URL, status, body);
+
+        let err = fetch_nirvana(&transport).await.unwrap_err();
+        assert!(format!("{err:?}").starts_with(variant), "{status}: {err:?}");
+        assert_eq!(err.status().unwrap().as_u16(), status);
+    }
+}
+
+#[tokio::test]
+async fn should_fall_back_to_the_status_reason() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 502, "<html>Bad Gateway</html>");
+
+    let err = fetch_nirvana(&transport).await.unwrap_err();
+    assert_eq!(err.api_error().unwrap().message, "Bad Gateway");
+}
+
+#[tokio::test]
+async fn should_reject_unexpected_content_types() {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+
+    let transport = FakeTransport::default();
+    transport.respond_with_headers(NIRVANA_URL, 200, headers, "<html>Maintenance</html>");
+
+    let err = fetch_nirvana(&transport).await.unwrap_err();
+    assert!(matches!(err, Error::UnexpectedContentType(_)));
+    assert_eq!(
+        err.api_error().unwrap().message,
+        r#"expected JSON, got "text/html""#
+    );
+}
+
+#[tokio::test]
+async fn should_keep_not_found_errors() {
+    let transport = FakeTransport::default();
+
+    let err = fetch_nirvana(&transport).await.unwrap_err();
+    assert!(matches!(&err, Error::NotFound(err) if err.url == NIRVANA_URL));
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(err.api_error().unwrap().message, "Not Found");
+}
+
+#[tokio::test]
+async fn should_report_the_path_of_deserialization_errors() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        NIRVANA_URL,
+        200,
+        r#"{
+            "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+            "name": "Nirvana",
+            "life-span": {"begin": "1987", "ended": "yes"}
+        }"#,
+    );
+
+    let Error::Deserialization(err) = fetch_nirvana(&transport).await.unwrap_err() else {
+        panic!("expected a deserialization error");
+    };
+
+    assert_eq!(err.path, "life-span.ended");
+    assert_eq!(err.url, NIRVANA_URL);
+    assert!(err.excerpt.contains(r#""ended": "yes""#), "{}", err.excerpt);
+}
@@ -0,0 +1,107 @@
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::StreamExt;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::transport::*;
+use musicbrainz_rs::Error;
+
+use crate::fake_transport::*;
+
+const IDS: [&str; 4] = [
+    "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+    "a74b1b7f-71a5-4011-9441-d0b5e4122711",
+    "8bfac288-ccc5-448d-9573-c33ea2aa5c30",
+    "b10bbbfc-cf9e-42e0-be17-e2c3e1d2600d",
+];
+
+fn artist_url(id: &str) -> String {
+    format!("http://musicbrainz.org/ws/2/artist/{id}?fmt=json&inc=aliases")
+}
+
+fn artist_json(id: &str, name: &str) -> String {
+    format!(r#"{{"id": "{id}", "name": "{name}", "aliases": []}}"#)
+}
+
+/// Answer the requests after a delay, and keep track of how many are sent at the same time
+#[derive(Debug, Clone, Default)]
+struct SlowTransport {
+    inner: FakeTransport,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl Transport for SlowTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Transport::send(&self.inner, request).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn should_fetch_many_entities_with_bounded_concurrency() {
+    let transport = SlowTransport::default();
+    transport
+        .inner
+        .respond(&artist_url(IDS[0]), 200, "not json");
+    for (i, id) in IDS.iter().enumerate().skip(1) {
+        transport.inner.respond(
+            &artist_url(id),
+            200,
+            &artist_json(id, &format!("Artist {i}")),
+        );
+    }
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+
+    let results: HashMap<String, Result<Artist, Error>> = Artist::fetch_many(IDS)
+        .with_aliases()
+        .concurrency(2)
+        .execute_with_client(&client)
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 4);
+    assert!(results[IDS[0]].is_err());
+    assert_eq!(results[IDS[3]].as_ref().unwrap().name, "Artist 3");
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn should_stop_fetching_many_once_cancelled() {
+    let transport = SlowTransport::default();
+    for id in IDS {
+        transport
+            .inner
+            .respond(&artist_url(id), 200, &artist_json(id, "Artist"));
+    }
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+
+    let mut artists = Artist::fetch_many(IDS)
+        .with_aliases()
+        .concurrency(1)
+        .execute_with_client(&client);
+    let cancel = artists.cancel_handle();
+
+    let (id, artist) = artists.next().await.unwrap();
+    assert_eq!(id, IDS[0]);
+    assert!(artist.is_ok());
+
+    cancel.cancel();
+    assert!(artists.next().await.is_none());
+    assert!(transport.inner.requests().len() < IDS.len());
+}
@@ -0,0 +1,101 @@
+use core::time::Duration;
+
+use futures::future::join_all;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::transport::*;
+use musicbrainz_rs::Error;
+
+use crate::fake_transport::*;
+
+/// Answer the requests after a delay, so they overlap
+#[derive(Debug, Clone)]
+struct SlowTransport(FakeTransport);
+
+impl Transport for SlowTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Transport::send(&self.0, request).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn should_send_identical_concurrent_requests_once() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(SlowTransport(transport.clone()));
+
+    let results = join_all((0..10).map(|_| async {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client(&client)
+            .await
+    }))
+    .await;
+
+    for result in results {
+        assert_eq!(result.unwrap().name, "Nirvana");
+    }
+    assert_eq!(transport.requests().len(), 1);
+
+    // Once done, the request is sent again
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_not_share_requests_without_cache() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(SlowTransport(transport.clone()));
+
+    let results = join_all((0..3).map(|_| async {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .no_cache()
+            .execute_with_client(&client)
+            .await
+    }))
+    .await;
+
+    for result in results {
+        assert_eq!(result.unwrap().name, "Nirvana");
+    }
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn should_not_share_requests_between_transports() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+    let other_transport = FakeTransport::default();
+    other_transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(SlowTransport(transport.clone()));
+    let mut other_client = client.clone();
+    other_client.set_transport(SlowTransport(other_transport.clone()));
+
+    let query = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .to_owned();
+    let (result, other_result) = tokio::join!(
+        query.execute_with_client(&client),
+        query.execute_with_client(&other_client)
+    );
+
+    assert_eq!(result.unwrap(), other_result.unwrap());
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(other_transport.requests().len(), 1);
+}
@@ -0,0 +1,118 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::release::*;
+use musicbrainz_rs::lenient::LenientMode;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+const RELEASE_URL: &str =
+    "http://musicbrainz.org/ws/2/release/b1dc9838-adf3-43f2-93f9-802b46e5fe59?fmt=json";
+
+/// A release with a script that doesn't exist yet, and a track length in an odd format
+fn malformed_release() -> FakeTransport {
+    let mut release: serde_json::Value = serde_json::from_str(include_str!(
+        "../../serde/data/lookup/release/b1dc9838-adf3-43f2-93f9-802b46e5fe59.json"
+    ))
+    .unwrap();
+    release["text-representation"]["script"] = "Zzzx".into();
+    release["media"][0]["tracks"][0]["length"] = "2:34".into();
+
+    let transport = FakeTransport::default();
+    transport.respond(RELEASE_URL, 200, &release.to_string());
+    transport
+}
+
+async fn fetch_release(client: &MusicBrainzClient) -> Result<Release, Error> {
+    Release::fetch()
+        .id("b1dc9838-adf3-43f2-93f9-802b46e5fe59")
+        .execute_with_client(client)
+        .await
+}
+
+#[tokio::test]
+async fn should_fail_on_malformed_fields_by_default() {
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(malformed_release());
+
+    let result = fetch_release(&client).await;
+    assert!(matches!(result, Err(Error::Deserialization(_))));
+}
+
+#[tokio::test]
+async fn should_drop_malformed_fields_when_lenient() {
+    let lenient = LenientMode::default();
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(malformed_release());
+    client.set_lenient(lenient.clone());
+
+    let release = fetch_release(&client).await.unwrap();
+
+    let text_representation = release.text_representation.unwrap();
+    assert_eq!(text_representation.script, None);
+    assert_eq!(text_representation.language, Some(Language::Eng));
+
+    let tracks = release.media.unwrap()[0].tracks.clone().unwrap();
+    assert_eq!(tracks[0].title, "Tallahassie Lassie");
+    assert_eq!(tracks[0].length, None);
+
+    let mut warnings = lenient.take_warnings();
+    warnings.sort_by(|a, b| a.path.cmp(&b.path));
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].path, "media[0].tracks[0].length");
+    assert_eq!(warnings[1].path, "text-representation.script");
+    assert_eq!(warnings[1].url, RELEASE_URL);
+}
+
+/// A release with a track which position isn't a number, so the whole track can't be deserialized
+fn release_with_malformed_track() -> FakeTransport {
+    let mut release: serde_json::Value = serde_json::from_str(include_str!(
+        "../../serde/data/lookup/release/b1dc9838-adf3-43f2-93f9-802b46e5fe59.json"
+    ))
+    .unwrap();
+    release["media"][0]["tracks"][0]["position"] = "A1".into();
+
+    let transport = FakeTransport::default();
+    transport.respond(RELEASE_URL, 200, &release.to_string());
+    transport
+}
+
+#[tokio::test]
+async fn should_not_remove_the_list_elements_by_default() {
+    let lenient = LenientMode::default();
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(release_with_malformed_track());
+    client.set_lenient(lenient.clone());
+
+    let result = fetch_release(&client).await;
+
+    let Err(Error::Deserialization(err)) = result else {
+        panic!("expected a deserialization error, got {result:?}");
+    };
+    assert_eq!(err.path, "media[0].tracks[0].position");
+    assert!(lenient.take_warnings().is_empty());
+}
+
+#[tokio::test]
+async fn should_remove_the_list_elements_when_allowed() {
+    let lenient = LenientMode::default().remove_list_elements();
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(release_with_malformed_track());
+    client.set_lenient(lenient.clone());
+
+    let release = fetch_release(&client).await.unwrap();
+    let original: Release = serde_json::from_str(include_str!(
+        "../../serde/data/lookup/release/b1dc9838-adf3-43f2-93f9-802b46e5fe59.json"
+    ))
+    .unwrap();
+
+    let tracks = release.media.unwrap()[0].tracks.clone().unwrap();
+    let original_tracks = original.media.unwrap()[0].tracks.clone().unwrap();
+    assert_eq!(tracks, original_tracks[1..]);
+
+    let paths: Vec<String> = lenient
+        .take_warnings()
+        .into_iter()
+        .map(|warning| warning.path)
+        .collect();
+    assert_eq!(paths, ["media[0].tracks[0].position", "media[0].tracks[0]"]);
+}
@@ -0,0 +1,74 @@
+use core::time::Duration;
+use std::sync::{Arc, Mutex};
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::entity::release::Release;
+use musicbrainz_rs::middleware::Middleware;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::transport::{HttpRequest, HttpResponse};
+use reqwest::header::HeaderValue;
+
+use crate::fake_transport::*;
+
+const IN_UTERO_FRONT_URL: &str =
+    "http://coverartarchive.org/release/76df3287-6cda-33eb-8e9a-044b5e15ffdd/front";
+
+#[derive(Debug, Default, Clone)]
+struct Recorder {
+    urls: Arc<Mutex<Vec<(String, u16)>>>,
+}
+
+impl Middleware for Recorder {
+    fn before_request(&self, request: &mut HttpRequest) {
+        request
+            .headers
+            .insert("x-test", HeaderValue::from_static("middleware"));
+    }
+
+    fn after_response(&self, request: &HttpRequest, response: &mut HttpResponse, _: Duration) {
+        self.urls
+            .lock()
+            .unwrap()
+            .push((request.url.clone(), response.status.as_u16()));
+    }
+}
+
+#[tokio::test]
+async fn should_run_middlewares_on_every_request() {
+    let transport = FakeTransport::default();
+    transport
+        .respond(NIRVANA_URL, 200, NIRVANA_JSON)
+        .respond(IN_UTERO_FRONT_URL, 200, "");
+
+    let recorder = Recorder::default();
+    let mut client = MusicBrainzClient::builder()
+        .middleware(recorder.clone())
+        .build()
+        .unwrap();
+    client.set_transport(transport.clone());
+
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+    Release::fetch_coverart()
+        .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
+        .front()
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.urls.lock().unwrap(),
+        vec![
+            (NIRVANA_URL.to_string(), 200),
+            (IN_UTERO_FRONT_URL.to_string(), 200)
+        ]
+    );
+    assert!(transport
+        .requests()
+        .iter()
+        .all(|request| request.headers.get("x-test").unwrap() == "middleware"));
+}
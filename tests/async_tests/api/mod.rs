@@ -1,3 +1,20 @@
+mod builder;
+mod cache;
+mod cassette;
 mod config;
+#[cfg(feature = "disk_cache")]
+mod disk_cache;
+mod dry_run;
+mod errors;
 mod fetch;
+mod fetch_many;
+mod in_flight;
+mod lenient;
+mod middleware;
+mod paging;
+mod raw;
+mod retry;
+mod reuse;
 mod search;
+mod search_by_ids;
+mod transport;
@@ -0,0 +1,55 @@
+use futures::TryStreamExt;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+const AREA_ID: &str = "489ce91b-6658-3307-9877-795b68554c98";
+
+fn page_url(offset: u32) -> String {
+    format!("http://musicbrainz.org/ws/2/artist?fmt=json&area={AREA_ID}&limit=100&offset={offset}")
+}
+
+#[tokio::test]
+async fn should_stream_all_browse_pages() {
+    let transport = FakeTransport::default();
+    transport
+        .respond(&page_url(0), 200, &artist_browse_page(0..100, 0, 150))
+        .respond(&page_url(100), 200, &artist_browse_page(100..150, 100, 150));
+
+    let client = client_with(&transport);
+
+    let artists: Vec<Artist> = Artist::browse()
+        .by_area(AREA_ID)
+        .stream_with_client(&client)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(artists.len(), 150);
+    assert_eq!(artists[149].name, "Artist 149");
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_stream_from_large_offsets() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        &page_url(70_000),
+        200,
+        &artist_browse_page(70_000..70_010, 70_000, 70_010),
+    );
+
+    let client = client_with(&transport);
+
+    let artists: Vec<Artist> = Artist::browse()
+        .by_area(AREA_ID)
+        .offset(70_000)
+        .stream_with_client(&client)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(artists.len(), 10);
+    assert_eq!(transport.requests().len(), 1);
+}
@@ -0,0 +1,99 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+
+use crate::fake_transport::*;
+
+const NIRVANA_WITH_NEW_FIELD: &str = r#"{
+    "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+    "name": "Nirvana",
+    "sort-name": "Nirvana",
+    "brand-new-field": {"count": 3}
+}"#;
+
+/// A transport answering the lookup of Nirvana with a field that isn't modelled
+fn nirvana_transport() -> FakeTransport {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+
+    let transport = FakeTransport::default();
+    transport.respond_with_headers(NIRVANA_URL, 200, headers, NIRVANA_WITH_NEW_FIELD);
+    transport
+}
+
+#[tokio::test]
+async fn should_return_the_raw_response() {
+    let transport = nirvana_transport();
+    let client = client_with(&transport);
+
+    let raw = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_raw_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(raw.status, StatusCode::OK);
+    assert_eq!(raw.url, NIRVANA_URL);
+    assert_eq!(raw.headers["x-ratelimit-remaining"], "42");
+    assert_eq!(raw.json["brand-new-field"]["count"], 3);
+}
+
+#[tokio::test]
+async fn should_return_the_entity_with_the_raw_response() {
+    let transport = nirvana_transport();
+    let client = client_with(&transport);
+
+    let (nirvana, raw) = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_raw_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(raw.json["name"], "Nirvana");
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn should_still_return_api_errors() {
+    let transport = FakeTransport::default();
+    let client = client_with(&transport);
+
+    let result = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_raw_with_client(&client)
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound(_))));
+}
+
+#[tokio::test]
+async fn should_return_the_raw_coverart_response() {
+    use musicbrainz_rs::entity::release::Release;
+    use musicbrainz_rs::entity::CoverartResponse;
+
+    let transport = FakeTransport::default();
+    let client = client_with(&transport);
+
+    let mut query = Release::fetch_coverart();
+    query.id("76df3287-6cda-33eb-8e9a-044b5e15ffdd");
+    let json_url = query.to_url(&client);
+    transport.respond(&json_url, 200, r#"{"images": [], "release": "new"}"#);
+
+    let mut image_query = query.clone();
+    image_query.front().res_250();
+    let image_url = image_query.to_url(&client);
+    transport.respond(&image_url, 200, "");
+
+    let raw = query.execute_raw_with_client(&client).await.unwrap();
+    assert_eq!(raw.json["release"], "new");
+
+    let (image, raw) = image_query
+        .execute_with_raw_with_client(&client)
+        .await
+        .unwrap();
+    assert!(matches!(image, CoverartResponse::Url(url) if url == image_url));
+    assert_eq!(raw.url, image_url);
+    assert!(raw.json.is_null());
+}
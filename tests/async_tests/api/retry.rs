@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::retry::RetryPolicy;
+use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use crate::fake_transport::*;
+
+/// A client retrying quickly, at most twice
+fn retrying_client_with(transport: &FakeTransport) -> MusicBrainzClient {
+    let mut client = client_with(transport);
+    client.retry_policy = RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    };
+    client
+}
+
+#[tokio::test]
+async fn should_retry_server_errors() {
+    let transport = FakeTransport::default();
+    transport
+        .respond(NIRVANA_URL, 503, "")
+        .respond(NIRVANA_URL, 502, "")
+        .respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&retrying_client_with(&transport))
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn should_give_up_after_max_retries() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 503, "");
+
+    let result = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&retrying_client_with(&transport))
+        .await;
+
+    assert!(matches!(result, Err(Error::ServiceUnavailable(_))));
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn should_keep_the_deprecated_max_retries() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 500, "");
+
+    let mut client = retrying_client_with(&transport);
+    client.max_retries = 1;
+    let _ = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await;
+
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_return_the_last_error_with_the_default_policy() {
+    let mut headers = HeaderMap::new();
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("0"));
+
+    let transport = FakeTransport::default();
+    transport.respond_with_headers(
+        NIRVANA_URL,
+        429,
+        headers,
+        r#"{"error": "Your requests are exceeding the allowable rate limit."}"#,
+    );
+
+    // The client side rate limiter would slow the retries down
+    let builder = MusicBrainzClient::builder();
+    #[cfg(feature = "rate_limit")]
+    let builder = builder.no_rate_limit();
+    let mut client = builder.build().unwrap();
+    client.set_transport(transport.clone());
+
+    let err = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::RateLimited(_)));
+    assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    assert_eq!(err.api_error().unwrap().url, NIRVANA_URL);
+    assert_eq!(
+        transport.requests().len(),
+        RetryPolicy::default().max_retries as usize + 1
+    );
+}
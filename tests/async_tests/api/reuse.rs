@@ -0,0 +1,73 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::entity::release::Release;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+const NIRVANA_ID: &str = "5b11f4ce-a62d-471e-81fc-a69a8278c7da";
+const RADIOHEAD_ID: &str = "a74b1b7f-71a5-4011-9441-d0b5e4122711";
+const IN_UTERO_FRONT_URL: &str =
+    "http://coverartarchive.org/release/76df3287-6cda-33eb-8e9a-044b5e15ffdd/front-250";
+
+fn artist_url(id: &str) -> String {
+    format!("http://musicbrainz.org/ws/2/artist/{id}?fmt=json&inc=aliases+tags")
+}
+
+#[tokio::test]
+async fn should_reuse_a_query_for_many_ids() {
+    let transport = FakeTransport::default();
+    transport
+        .respond(&artist_url(NIRVANA_ID), 200, NIRVANA_JSON)
+        .respond(
+            &artist_url(RADIOHEAD_ID),
+            200,
+            &format!(r#"{{"id": "{RADIOHEAD_ID}", "name": "Radiohead"}}"#),
+        );
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+
+    let mut template = Artist::fetch();
+    template.with_tags().with_aliases();
+
+    let nirvana = template
+        .clone()
+        .id(NIRVANA_ID)
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+    let radiohead = template
+        .clone()
+        .with_aliases()
+        .id(NIRVANA_ID)
+        .id(RADIOHEAD_ID)
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(radiohead.name, "Radiohead");
+}
+
+#[tokio::test]
+async fn should_execute_the_same_coverart_query_twice() {
+    let transport = FakeTransport::default();
+    transport.respond(IN_UTERO_FRONT_URL, 200, "");
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+
+    let mut query = Release::fetch_coverart();
+    query.res_250().id("76df3287-6cda-33eb-8e9a-044b5e15ffdd");
+
+    for _ in 0..2 {
+        query.execute_with_client(&client).await.unwrap();
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests
+        .iter()
+        .all(|request| request.url == IN_UTERO_FRONT_URL));
+}
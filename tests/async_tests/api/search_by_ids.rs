@@ -0,0 +1,41 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+const IDS: [&str; 3] = [
+    "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+    "a74b1b7f-71a5-4011-9441-d0b5e4122711",
+    "8bfac288-ccc5-448d-9573-c33ea2aa5c30",
+];
+
+#[tokio::test]
+async fn should_search_many_entities_by_id_at_once() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        &format!(
+            "http://musicbrainz.org/ws/2/artist?fmt=json&query=arid%3A{}+OR+arid%3A{}+OR+arid%3A{}&limit=100&offset=0",
+            IDS[0], IDS[1], IDS[2]
+        ),
+        200,
+        &format!(
+            r#"{{"created": "2024-01-01T00:00:00.000Z", "count": 2, "offset": 0, "artists": [
+                {{"id": "{}", "name": "Nirvana"}},
+                {{"id": "{}", "name": "Radiohead"}}
+            ]}}"#,
+            IDS[0], IDS[1]
+        ),
+    );
+
+    let client = client_with(&transport);
+
+    let artists = Artist::search_by_ids(IDS)
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    let names: Vec<_> = artists.entities.iter().map(|artist| &artist.name).collect();
+    assert_eq!(names, ["Nirvana", "Radiohead"]);
+    assert_eq!(artists.not_found, [IDS[2]]);
+    assert_eq!(transport.requests().len(), 1);
+}
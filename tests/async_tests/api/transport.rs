@@ -0,0 +1,91 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use reqwest::header::USER_AGENT;
+
+use crate::fake_transport::*;
+
+#[tokio::test]
+async fn should_fetch_with_custom_transport() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_user_agent("musicbrainz_rs_testing/0.9").unwrap();
+    client.set_transport(transport.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(nirvana.artist_type, Some(ArtistType::Group));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, NIRVANA_URL);
+    assert_eq!(
+        requests[0].headers.get(USER_AGENT).unwrap(),
+        "musicbrainz_rs_testing/0.9"
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn should_use_async_and_blocking_apis_side_by_side() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+    client.set_blocking_transport(transport.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client(&client)
+        .await
+        .unwrap();
+
+    let blocking_nirvana = tokio::task::spawn_blocking(move || {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client_blocking(&client)
+            .unwrap()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(nirvana, blocking_nirvana);
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_skip_the_body_of_coverart_images() {
+    use musicbrainz_rs::entity::release::Release;
+    use musicbrainz_rs::entity::CoverartResponse;
+
+    let transport = FakeTransport::default();
+    let mut client = MusicBrainzClient::default();
+    client.set_transport(transport.clone());
+
+    let mut query = Release::fetch_coverart();
+    query.id("76df3287-6cda-33eb-8e9a-044b5e15ffdd");
+    let json_url = query.to_url(&client);
+    transport.respond(&json_url, 200, r#"{"images": [], "release": ""}"#);
+
+    let mut image_query = query.clone();
+    image_query.front().res_250();
+    let image_url = image_query.to_url(&client);
+    transport.respond(&image_url, 200, "");
+
+    query.execute_with_client(&client).await.unwrap();
+    let image = image_query.execute_with_client(&client).await.unwrap();
+
+    assert!(matches!(image, CoverartResponse::Url(url) if url == image_url));
+    let requests = transport.requests();
+    assert!(!requests[0].skip_body);
+    assert!(requests[1].skip_body);
+    assert!(image_query.dry_run(&client).skip_body);
+}
@@ -7,7 +7,7 @@ fn should_search_annotation() {
         .text("Warner Classics International")
         .build();
 
-    let result = Annotation::search(query).execute().unwrap();
+    let result = Annotation::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
@@ -0,0 +1,30 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_fetch_with_built_client() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        "https://test.musicbrainz.org/ws/2/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json",
+        200,
+        NIRVANA_JSON,
+    );
+
+    let mut client = MusicBrainzClient::builder()
+        .user_agent("musicbrainz_rs_testing/0.9")
+        .musicbrainz_url("https://test.musicbrainz.org/ws/2")
+        .build()
+        .unwrap();
+    client.set_blocking_transport(transport.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&client)
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(transport.requests().len(), 1);
+}
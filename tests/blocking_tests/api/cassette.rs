@@ -0,0 +1,41 @@
+use musicbrainz_rs::cassette::Cassette;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_replay_recorded_responses() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nirvana.json");
+
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut recording = MusicBrainzClient::builder()
+        .cassette(Cassette::record(&path))
+        .build()
+        .unwrap();
+    recording.set_blocking_transport(transport.clone());
+
+    Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&recording)
+        .unwrap();
+
+    let offline = FakeTransport::default();
+    let mut replaying = MusicBrainzClient::builder()
+        .cassette(Cassette::replay(&path).unwrap())
+        .build()
+        .unwrap();
+    replaying.set_blocking_transport(offline.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&replaying)
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert!(offline.requests().is_empty());
+}
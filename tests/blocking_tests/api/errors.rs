@@ -0,0 +1,28 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::retry::RetryPolicy;
+use reqwest::StatusCode;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_return_typed_api_errors() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        NIRVANA_URL,
+        503,
+        r#"{"error": "Your requests are exceeding the allowable rate limit."}"#,
+    );
+
+    let mut client = client_with(&transport);
+    client.retry_policy = RetryPolicy::no_retry();
+
+    let err = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&client)
+        .unwrap_err();
+
+    assert!(matches!(err, Error::RateLimited(_)));
+    assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(err.api_error().unwrap().url, NIRVANA_URL);
+}
@@ -21,16 +21,34 @@ use musicbrainz_rs::entity::url::*;
 use musicbrainz_rs::entity::work::*;
 use musicbrainz_rs::prelude::*;
 
+/// The fields of the response that the entities don't model
+#[cfg(feature = "extra_fields")]
+fn extra(fields: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    let serde_json::Value::Object(fields) = fields else {
+        panic!("expected an object, got {fields}");
+    };
+    fields
+}
+
+/// The sorted keys of the fields that the entities don't model
+#[cfg(feature = "extra_fields")]
+fn extra_keys(fields: &serde_json::Map<String, serde_json::Value>) -> Vec<&str> {
+    let mut keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    keys
+}
+
 #[test]
 fn should_get_artist_by_id() {
     let nirvana = Artist::fetch()
         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        nirvana.unwrap(),
+        nirvana,
         Artist {
-            id: String::from("5b11f4ce-a62d-471e-81fc-a69a8278c7da"),
+            id: "5b11f4ce-a62d-471e-81fc-a69a8278c7da".parse().unwrap(),
             name: String::from("Nirvana"),
             sort_name: String::from("Nirvana"),
             disambiguation: String::from("1980s\u{2013}1990s US grunge band"),
@@ -38,7 +56,7 @@ fn should_get_artist_by_id() {
             gender: None,
             country: Some("US".to_string()),
             area: Some(Area {
-                id: "489ce91b-6658-3307-9877-795b68554c98".to_string(),
+                id: "489ce91b-6658-3307-9877-795b68554c98".parse().unwrap(),
                 area_type: None,
                 type_id: None,
                 disambiguation: "".to_string(),
@@ -51,12 +69,16 @@ fn should_get_artist_by_id() {
                 aliases: None,
                 genres: None,
                 annotation: None,
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             begin_area: None,
             life_span: Some(LifeSpan {
                 ended: Some(true),
                 begin: Some(NaiveDate::from_ymd_opt(1987, 1, 1).unwrap()),
                 end: Some(NaiveDate::from_ymd_opt(1994, 4, 5).unwrap()),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             tags: None,
             relations: None,
@@ -68,6 +90,22 @@ fn should_get_artist_by_id() {
             rating: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: extra(serde_json::json!({
+                "type-id": "e431f5f6-b5d2-343d-8b36-72607fffb74b",
+                "gender-id": null,
+                "begin-area": {
+                    "id": "a640b45c-c173-49b1-8030-973603e895b5",
+                    "name": "Aberdeen",
+                    "sort-name": "Aberdeen",
+                    "disambiguation": "",
+                    "type": null,
+                    "type-id": null
+                },
+                "end-area": null,
+                "isnis": ["0000000123486830", "0000000123487390"],
+                "ipis": []
+            })),
         }
     );
 }
@@ -77,7 +115,7 @@ fn should_get_artist_relations_from_release() {
     let in_utero = Release::fetch()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = in_utero.relations.unwrap();
@@ -88,7 +126,7 @@ fn should_get_artist_relations_from_release() {
             end: None,
             attributes: Some(vec![]),
             content: RelationContent::Artist(Box::new(Artist {
-                id: "0944a9f5-65be-44b6-9e8e-33732fdfe923".to_string(),
+                id: "0944a9f5-65be-44b6-9e8e-33732fdfe923".parse().unwrap(),
                 name: "Dave McDonald".to_string(),
                 sort_name: "McDonald, Dave".to_string(),
                 disambiguation: "sound engineer for Portishead".to_string(),
@@ -107,7 +145,11 @@ fn should_get_artist_relations_from_release() {
                 rating: None,
                 country: None,
                 annotation: None,
-                life_span: None
+                life_span: None,
+                #[cfg(feature = "extra_fields")]
+                extra: extra(serde_json::json!({
+                    "type-id": "b6e035f4-3ce9-331c-97df-83397230b0df"
+                })),
             })),
             attribute_values: Some(HashMap::new()),
             attribute_ids: Some(HashMap::new()),
@@ -115,11 +157,13 @@ fn should_get_artist_relations_from_release() {
             target_credit: Some("".to_string()),
             source_credit: Some("".to_string()),
             ended: Some(false),
-            type_id: "87e922ba-872e-418a-9f41-0a63aa3c30cc".to_string(),
+            type_id: "87e922ba-872e-418a-9f41-0a63aa3c30cc".parse().unwrap(),
             begin: None,
             direction: "backward".to_string(),
             relation_type: "engineer".to_string(),
-            ordering_key: None
+            ordering_key: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }]
     );
 }
@@ -128,12 +172,13 @@ fn should_get_artist_relations_from_release() {
 fn should_get_recording_by_id() {
     let polly = Recording::fetch()
         .id("af40d6b8-58e8-4ca5-9db8-d4fca0b899e2")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        polly.unwrap(),
+        polly,
         Recording {
-            id: "af40d6b8-58e8-4ca5-9db8-d4fca0b899e2".to_string(),
+            id: "af40d6b8-58e8-4ca5-9db8-d4fca0b899e2".parse().unwrap(),
             title: "(New Wave) Polly".to_string(),
             video: Some(false),
             length: Some(246_000),
@@ -148,6 +193,8 @@ fn should_get_recording_by_id() {
             annotation: None,
             isrcs: None,
             first_release_date: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -156,13 +203,14 @@ fn should_get_recording_by_id() {
 fn should_get_release_group_by_id() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        in_utero.unwrap(),
+        in_utero,
         ReleaseGroup {
-            id: "2a0981fb-9593-3019-864b-ce934d97a16e".to_string(),
-            primary_type_id: Some("f529b476-6e62-324f-b0aa-1f3e33d313fc".to_string()),
+            id: "2a0981fb-9593-3019-864b-ce934d97a16e".parse().unwrap(),
+            primary_type_id: Some("f529b476-6e62-324f-b0aa-1f3e33d313fc".parse().unwrap()),
             primary_type: Some(ReleaseGroupPrimaryType::Album),
             secondary_type_ids: vec![],
             secondary_types: vec![],
@@ -177,6 +225,8 @@ fn should_get_release_group_by_id() {
             rating: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -185,21 +235,25 @@ fn should_get_release_group_by_id() {
 fn should_get_release() {
     let in_utero = Release::fetch()
         .id("18d4e9b4-9247-4b44-914a-8ddec3502103")
-        .execute();
+        .execute_blocking()
+        .unwrap();
+
+    #[cfg(feature = "extra_fields")]
+    assert_eq!(extra_keys(&in_utero.extra), ["cover-art-archive"]);
 
     assert_eq!(
-        in_utero.unwrap(),
+        in_utero,
         Release {
-            id: "18d4e9b4-9247-4b44-914a-8ddec3502103".to_string(),
+            id: "18d4e9b4-9247-4b44-914a-8ddec3502103".parse().unwrap(),
             title: "In Utero".to_string(),
-            status_id: Some("4e304316-386d-3409-af2e-78857eec5cfe".to_string()),
+            status_id: Some("4e304316-386d-3409-af2e-78857eec5cfe".parse().unwrap()),
             status: Some(ReleaseStatus::Official),
             date: Some(NaiveDate::from_ymd_opt(1993, 1, 1).unwrap()),
             country: Some("US".to_string()),
             quality: Some(ReleaseQuality::Normal),
             barcode: Some("0208314671259".to_string()),
             disambiguation: Some("".to_string()),
-            packaging_id: Some("ec27701a-4a22-37f4-bfac-6616e0f9750a".to_string()),
+            packaging_id: Some("ec27701a-4a22-37f4-bfac-6616e0f9750a".parse().unwrap()),
             packaging: Some(ReleasePackaging::JewelCase),
             relations: None,
             artist_credit: None,
@@ -213,8 +267,12 @@ fn should_get_release() {
             text_representation: Some(ReleaseTextRepresentation {
                 script: Some(ReleaseScript::Latn),
                 language: Some(Language::Eng),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             asin: None,
+            #[cfg(feature = "extra_fields")]
+            extra: in_utero.extra.clone(),
         }
     );
 }
@@ -223,14 +281,57 @@ fn should_get_release() {
 fn should_get_work_by_id() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
-        .execute();
+        .execute_blocking()
+        .unwrap();
+
+    let attributes: Vec<WorkAttribute> = hotel_california
+        .attributes
+        .iter()
+        .flatten()
+        .map(|entry| entry.attribute.clone())
+        .collect();
+    assert_eq!(
+        attributes,
+        [
+            WorkAttribute::AcdamId("847166".to_string()),
+            WorkAttribute::CompassId("9963301".to_string()),
+            WorkAttribute::CompassId("76791".to_string()),
+            WorkAttribute::CompassId("6482326".to_string()),
+            WorkAttribute::CompassId("4487395".to_string()),
+            WorkAttribute::AgaduId("5846801".to_string()),
+            WorkAttribute::ApdaycId("2234367".to_string()),
+            WorkAttribute::SaycoId("1434791".to_string()),
+            WorkAttribute::EcadId("3077".to_string()),
+            WorkAttribute::SpaId("21287".to_string()),
+            WorkAttribute::PrsTuneCode("20565CR".to_string()),
+            WorkAttribute::SabamId("005891100".to_string()),
+            WorkAttribute::SacmId("030562613".to_string()),
+            WorkAttribute::BumaStemraId("W-000307506".to_string()),
+            WorkAttribute::SadaicId("252202".to_string()),
+            WorkAttribute::SacemId("76 184 283 11".to_string()),
+            WorkAttribute::SgaeId("121.617".to_string()),
+            WorkAttribute::CashId("C-1201894773".to_string()),
+            WorkAttribute::CashId("C-1001388311".to_string()),
+            WorkAttribute::SuisaId("000420 692 06".to_string()),
+            WorkAttribute::ApraId("GW00991714".to_string()),
+            WorkAttribute::SocanId("10303355".to_string()),
+            WorkAttribute::GemaId("910886-001".to_string()),
+            WorkAttribute::BmiId("2158644".to_string()),
+            WorkAttribute::AscapId("380174570".to_string()),
+        ]
+    );
+    // The ids of the attribute types are kept along with them
+    #[cfg(feature = "extra_fields")]
+    for entry in hotel_california.attributes.iter().flatten() {
+        assert!(entry.extra.contains_key("type-id"));
+    }
 
     assert_eq!(
-        hotel_california.unwrap(),
+        hotel_california,
         Work {
-            id: "22457dc0-ecbf-38f5-9056-11c858530a50".to_string(),
+            id: "22457dc0-ecbf-38f5-9056-11c858530a50".parse().unwrap(),
             title: "Hotel California".to_string(),
-            type_id: Some("f061270a-2fd6-32f1-a641-f0f8676d14e6".to_string()),
+            type_id: Some("f061270a-2fd6-32f1-a641-f0f8676d14e6".parse().unwrap()),
             work_type: Some(WorkType::Song),
             languages: Some(vec!["eng".to_string()]),
             language: Some("eng".to_string()),
@@ -239,33 +340,7 @@ fn should_get_work_by_id() {
                 "T-900.316.249-0".to_string(),
                 "T-900.316.251-4".to_string()
             ]),
-            attributes: Some(vec![
-                WorkAttribute::AcdamId("847166".to_string()),
-                WorkAttribute::CompassId("9963301".to_string()),
-                WorkAttribute::CompassId("76791".to_string()),
-                WorkAttribute::CompassId("6482326".to_string()),
-                WorkAttribute::CompassId("4487395".to_string()),
-                WorkAttribute::AgaduId("5846801".to_string()),
-                WorkAttribute::ApdaycId("2234367".to_string()),
-                WorkAttribute::SaycoId("1434791".to_string()),
-                WorkAttribute::EcadId("3077".to_string()),
-                WorkAttribute::SpaId("21287".to_string()),
-                WorkAttribute::PrsTuneCode("20565CR".to_string()),
-                WorkAttribute::SabamId("005891100".to_string()),
-                WorkAttribute::SacmId("030562613".to_string()),
-                WorkAttribute::BumaStemraId("W-000307506".to_string()),
-                WorkAttribute::SadaicId("252202".to_string()),
-                WorkAttribute::SacemId("76 184 283 11".to_string()),
-                WorkAttribute::SgaeId("121.617".to_string()),
-                WorkAttribute::CashId("C-1201894773".to_string()),
-                WorkAttribute::CashId("C-1001388311".to_string()),
-                WorkAttribute::SuisaId("000420 692 06".to_string()),
-                WorkAttribute::ApraId("GW00991714".to_string()),
-                WorkAttribute::SocanId("10303355".to_string()),
-                WorkAttribute::GemaId("910886-001".to_string()),
-                WorkAttribute::BmiId("2158644".to_string()),
-                WorkAttribute::AscapId("380174570".to_string()),
-            ]),
+            attributes: hotel_california.attributes.clone(),
             disambiguation: Some("".to_string()),
             relations: None,
             tags: None,
@@ -273,6 +348,8 @@ fn should_get_work_by_id() {
             rating: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -281,13 +358,20 @@ fn should_get_work_by_id() {
 fn should_get_label_by_id() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
-        .execute();
+        .execute_blocking()
+        .unwrap();
+
+    #[cfg(feature = "extra_fields")]
+    assert_eq!(
+        extra_keys(&ninja_tune.extra),
+        ["area", "ipis", "isnis", "life-span"]
+    );
 
     assert_eq!(
-        ninja_tune.unwrap(),
+        ninja_tune,
         Label {
-            id: "dc940013-b8a8-4362-a465-291026c04b42".to_string(),
-            type_id: Some("7aaa37fe-2def-3476-b359-80245850062d".to_string()),
+            id: "dc940013-b8a8-4362-a465-291026c04b42".parse().unwrap(),
+            type_id: Some("7aaa37fe-2def-3476-b359-80245850062d".parse().unwrap()),
             label_type: Some(LabelType::OriginalProduction),
             name: "Ninja Tune".to_string(),
             sort_name: Some("Ninja Tune".to_string()),
@@ -301,6 +385,8 @@ fn should_get_label_by_id() {
             rating: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: ninja_tune.extra.clone(),
         }
     );
 }
@@ -309,14 +395,15 @@ fn should_get_label_by_id() {
 fn should_get_area_by_id() {
     let aberdeen = Area::fetch()
         .id("a640b45c-c173-49b1-8030-973603e895b5")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        aberdeen.unwrap(),
+        aberdeen,
         Area {
-            id: "a640b45c-c173-49b1-8030-973603e895b5".to_string(),
+            id: "a640b45c-c173-49b1-8030-973603e895b5".parse().unwrap(),
             area_type: Some(City),
-            type_id: Some("6fd8f29a-3d0a-32fc-980d-ea697b69da78".to_string()),
+            type_id: Some("6fd8f29a-3d0a-32fc-980d-ea697b69da78".parse().unwrap()),
             disambiguation: "".to_string(),
             name: "Aberdeen".to_string(),
             sort_name: "Aberdeen".to_string(),
@@ -330,7 +417,11 @@ fn should_get_area_by_id() {
                 ended: Some(false),
                 begin: None,
                 end: None,
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -339,15 +430,16 @@ fn should_get_area_by_id() {
 fn should_get_event_by_id() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        dour_festival_1989.unwrap(),
+        dour_festival_1989,
         Event {
-            id: "73df2f48-383b-4930-bad3-05ba938be578".to_string(),
+            id: "73df2f48-383b-4930-bad3-05ba938be578".parse().unwrap(),
             name: "Dour 1989".to_string(),
             cancelled: Some(false),
-            type_id: Some("b6ded574-b592-3f0e-b56e-5b5f06aa0678".to_string()),
+            type_id: Some("b6ded574-b592-3f0e-b56e-5b5f06aa0678".parse().unwrap()),
             event_type: Some(EventType::Festival),
             disambiguation: Some("".to_string()),
             time: Some("".to_string()),
@@ -356,6 +448,8 @@ fn should_get_event_by_id() {
                 begin: Some(NaiveDate::from_ymd_opt(1989, 9, 16).unwrap()),
                 end: Some(NaiveDate::from_ymd_opt(1989, 9, 16).unwrap()),
                 ended: Some(true),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             relations: None,
             tags: None,
@@ -363,6 +457,8 @@ fn should_get_event_by_id() {
             rating: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -371,15 +467,16 @@ fn should_get_event_by_id() {
 fn should_get_instrument() {
     let mandoline = Instrument::fetch()
         .id("37fa9bb5-d5d7-4b0f-aa4d-531339ba9c32")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        mandoline.unwrap(),
+        mandoline,
         Instrument {
-            id: "37fa9bb5-d5d7-4b0f-aa4d-531339ba9c32".to_string(),
+            id: "37fa9bb5-d5d7-4b0f-aa4d-531339ba9c32".parse().unwrap(),
             name: "mandolin".to_string(),
             instrument_type: StringInstrument,
-            type_id: "cc00f97f-cf3d-3ae2-9163-041cb1a0d726".to_string(),
+            type_id: "cc00f97f-cf3d-3ae2-9163-041cb1a0d726".parse().unwrap(),
             description: Some("".to_string()),
             disambiguation: Some("".to_string()),
             relations: None,
@@ -387,6 +484,8 @@ fn should_get_instrument() {
             aliases: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -395,24 +494,27 @@ fn should_get_instrument() {
 fn should_get_place() {
     let blue_note_record = Place::fetch()
         .id("327c29c6-da63-4dc9-a117-1917ee691ce4")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        blue_note_record.unwrap(),
+        blue_note_record,
         Place {
-            id: "327c29c6-da63-4dc9-a117-1917ee691ce4".to_string(),
+            id: "327c29c6-da63-4dc9-a117-1917ee691ce4".parse().unwrap(),
             name: "Blue Note".to_string(),
             disambiguation: Some("Chicago, 1954-1960".to_string()),
             life_span: Some(LifeSpan {
                 begin: Some(NaiveDate::from_ymd_opt(1954, 4, 2).unwrap()),
                 end: Some(NaiveDate::from_ymd_opt(1960, 6, 14).unwrap()),
                 ended: Some(true),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
-            type_id: Some("cd92781a-a73f-30e8-a430-55d7521338db".to_string()),
+            type_id: Some("cd92781a-a73f-30e8-a430-55d7521338db".parse().unwrap()),
             place_type: Some(Venue),
             address: Some("3 North Clark Street, Chicago, IL 60602".to_string()),
             area: Some(Area {
-                id: "29a709d8-0320-493e-8d0c-f2c386662b7f".to_string(),
+                id: "29a709d8-0320-493e-8d0c-f2c386662b7f".parse().unwrap(),
                 disambiguation: "".to_string(),
                 sort_name: "Chicago".to_string(),
                 name: "Chicago".to_string(),
@@ -425,16 +527,22 @@ fn should_get_place() {
                 aliases: None,
                 genres: None,
                 annotation: None,
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             coordinates: Some(Coordinates {
                 latitude: 41.882_059.into(),
                 longitude: (-87.630_881).into(),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             }),
             relations: None,
             aliases: None,
             tags: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -443,13 +551,14 @@ fn should_get_place() {
 fn should_get_series() {
     let la_chanson_du_dimanche = Series::fetch()
         .id("814fb4d5-327f-4e37-8784-f8a707e5f97c")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        la_chanson_du_dimanche.unwrap(),
+        la_chanson_du_dimanche,
         Series {
-            id: "814fb4d5-327f-4e37-8784-f8a707e5f97c".to_string(),
-            type_id: "dd968243-7128-30a2-81f0-79843430a8e2".to_string(),
+            id: "814fb4d5-327f-4e37-8784-f8a707e5f97c".parse().unwrap(),
+            type_id: "dd968243-7128-30a2-81f0-79843430a8e2".parse().unwrap(),
             series_type: SeriesType::RecordingSeries,
             disambiguation: "".to_string(),
             name: "La Chanson du Dimanche — Saison 4".to_string(),
@@ -458,6 +567,8 @@ fn should_get_series() {
             aliases: None,
             genres: None,
             annotation: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -466,14 +577,17 @@ fn should_get_series() {
 fn should_get_url() {
     let svinkels_dot_com = Url::fetch()
         .id("9237f6da-fec6-4b8a-9d52-c7c18e0e2630")
-        .execute();
+        .execute_blocking()
+        .unwrap();
 
     assert_eq!(
-        svinkels_dot_com.unwrap(),
+        svinkels_dot_com,
         Url {
             resource: "http://www.svinkels.com/".to_string(),
-            id: "9237f6da-fec6-4b8a-9d52-c7c18e0e2630".to_string(),
+            id: "9237f6da-fec6-4b8a-9d52-c7c18e0e2630".parse().unwrap(),
             tags: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
         }
     );
 }
@@ -0,0 +1,39 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+const IDS: [&str; 3] = [
+    "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+    "a74b1b7f-71a5-4011-9441-d0b5e4122711",
+    "8bfac288-ccc5-448d-9573-c33ea2aa5c30",
+];
+
+#[test]
+fn should_fetch_many_entities_one_after_the_other() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON).respond(
+        &format!("http://musicbrainz.org/ws/2/artist/{}?fmt=json", IDS[1]),
+        200,
+        &format!(r#"{{"id": "{}", "name": "Radiohead"}}"#, IDS[1]),
+    );
+
+    let mut client = MusicBrainzClient::default();
+    client.set_blocking_transport(transport.clone());
+
+    let mut artists = Artist::fetch_many(IDS).execute_with_client_blocking(&client);
+    let cancel = artists.cancel_handle();
+
+    let (id, nirvana) = artists.next().unwrap();
+    assert_eq!(id, IDS[0]);
+    assert_eq!(nirvana.unwrap().name, "Nirvana");
+
+    let (id, radiohead) = artists.next().unwrap();
+    assert_eq!(id, IDS[1]);
+    assert_eq!(radiohead.unwrap().name, "Radiohead");
+
+    cancel.cancel();
+    assert!(artists.next().is_none());
+    assert_eq!(transport.requests().len(), 2);
+}
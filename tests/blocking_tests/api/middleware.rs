@@ -0,0 +1,40 @@
+use core::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::middleware::Middleware;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::transport::{HttpRequest, HttpResponse};
+
+use crate::fake_transport::*;
+
+#[derive(Debug, Default, Clone)]
+struct CountResponses(Arc<AtomicUsize>);
+
+impl Middleware for CountResponses {
+    fn after_response(&self, _: &HttpRequest, _: &mut HttpResponse, _: Duration) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn should_run_middlewares_on_every_request() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let counter = CountResponses::default();
+    let mut client = MusicBrainzClient::default();
+    client.add_middleware(counter.clone());
+    client.set_blocking_transport(transport);
+
+    for _ in 0..2 {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client_blocking(&client)
+            .unwrap();
+    }
+
+    assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+}
@@ -1,3 +1,18 @@
+mod builder;
+mod cache;
+mod cassette;
 mod config;
+mod errors;
 mod fetch;
+mod fetch_many;
+mod middleware;
+mod paging;
+#[cfg(feature = "rate_limit")]
+mod rate_limit;
+mod raw;
+mod retry;
 mod search;
+mod search_by_ids;
+#[cfg(feature = "tracing")]
+mod tracing;
+mod transport;
@@ -0,0 +1,27 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_iterate_over_all_search_pages() {
+    let query = ArtistSearchQuery::query_builder().artist("Artist").build();
+    let page_url = |offset: u32| {
+        format!("http://musicbrainz.org/ws/2/artist?fmt=json&query=artist%3AArtist&limit=100&offset={offset}")
+    };
+
+    let transport = FakeTransport::default();
+    transport
+        .respond(&page_url(0), 200, &artist_search_page(0..100, 0, 120))
+        .respond(&page_url(100), 200, &artist_search_page(100..120, 100, 120));
+
+    let client = client_with(&transport);
+
+    let artists = Artist::search(query.clone())
+        .iter_with_client_blocking(&client)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(artists.len(), 120);
+    assert_eq!(transport.requests().len(), 2);
+}
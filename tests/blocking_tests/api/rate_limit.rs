@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use governor::Quota;
+use governor::RateLimiter;
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_wait_for_ratelimit() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_blocking_transport(transport.clone());
+    client.rate_limit = Some(Arc::new(RateLimiter::direct(
+        Quota::with_period(Duration::from_millis(100)).unwrap(),
+    )));
+
+    let start = Instant::now();
+    for _ in 0..4 {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute_with_client_blocking(&client)
+            .unwrap();
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(transport.requests().len(), 4);
+}
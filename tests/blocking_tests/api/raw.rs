@@ -0,0 +1,33 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_return_the_entity_with_the_raw_response() {
+    let transport = FakeTransport::default();
+    transport.respond(
+        "http://musicbrainz.org/ws/2/artist?fmt=json&query=artist%3ANirvana",
+        200,
+        r#"{
+            "created": "2024-01-01T00:00:00.000Z",
+            "count": 1,
+            "offset": 0,
+            "artists": [{"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana", "score": 100}]
+        }"#,
+    );
+
+    let client = client_with(&transport);
+
+    let (result, raw) = Artist::search("artist:Nirvana".to_string())
+        .execute_with_raw_with_client_blocking(&client)
+        .unwrap();
+
+    assert_eq!(result.entities[0].name, "Nirvana");
+    assert_eq!(raw.json["artists"][0]["score"], 100);
+
+    let raw = Artist::search("artist:Nirvana".to_string())
+        .execute_raw_with_client_blocking(&client)
+        .unwrap();
+    assert_eq!(raw.json["count"], 1);
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::retry::RetryPolicy;
+
+use crate::fake_transport::*;
+
+/// A client retrying quickly, at most twice
+fn retrying_client_with(transport: &FakeTransport) -> MusicBrainzClient {
+    let mut client = client_with(transport);
+    client.retry_policy = RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    };
+    client
+}
+
+#[test]
+fn should_retry_server_errors() {
+    let transport = FakeTransport::default();
+    transport
+        .respond(NIRVANA_URL, 503, "")
+        .respond(NIRVANA_URL, 502, "")
+        .respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&retrying_client_with(&transport))
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[test]
+fn should_give_up_after_max_retries() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 503, "");
+
+    let result = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&retrying_client_with(&transport));
+
+    assert!(matches!(result, Err(Error::ServiceUnavailable(_))));
+    assert_eq!(transport.requests().len(), 3);
+}
@@ -11,7 +11,7 @@ fn should_paginate_search() {
     let result = Recording::search(query.clone())
         .offset(0)
         .limit(10)
-        .execute()
+        .execute_blocking()
         .unwrap();
     assert!(result.count > 0);
     assert!(result.entities.len() == 10);
@@ -19,7 +19,7 @@ fn should_paginate_search() {
     let result_next = Recording::search(query)
         .offset(10)
         .limit(10)
-        .execute()
+        .execute_blocking()
         .unwrap();
     assert!(result_next.count > 0);
     assert!(result_next.entities.len() == 10);
@@ -0,0 +1,40 @@
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+
+use crate::fake_transport::*;
+
+fn search_url(ids: &[String]) -> String {
+    let query: Vec<String> = ids.iter().map(|id| format!("arid%3A{id}")).collect();
+    format!(
+        "http://musicbrainz.org/ws/2/artist?fmt=json&query={}&limit=100&offset=0",
+        query.join("+OR+")
+    )
+}
+
+#[test]
+fn should_split_the_ids_in_chunks_of_100() {
+    let ids: Vec<String> = (0..150).map(artist_id).collect();
+
+    let transport = FakeTransport::default();
+    transport
+        .respond(
+            &search_url(&ids[..100]),
+            200,
+            &artist_search_page(0..100, 0, 100),
+        )
+        .respond(
+            &search_url(&ids[100..]),
+            200,
+            &artist_search_page(100..149, 0, 49),
+        );
+
+    let client = client_with(&transport);
+
+    let artists = Artist::search_by_ids(&ids)
+        .execute_with_client_blocking(&client)
+        .unwrap();
+
+    assert_eq!(artists.entities.len(), 149);
+    assert_eq!(artists.not_found, [ids[149].clone()]);
+    assert_eq!(transport.requests().len(), 2);
+}
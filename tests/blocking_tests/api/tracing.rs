@@ -0,0 +1,88 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use musicbrainz_rs::retry::RetryPolicy;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+use crate::fake_transport::*;
+
+/// Collect all the fields of the spans and events, as `name=value` strings
+#[derive(Debug, Default, Clone)]
+struct FieldsLayer {
+    fields: Arc<Mutex<Vec<String>>>,
+}
+
+impl Visit for FieldsLayer {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.fields
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for FieldsLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        attrs.record(&mut self.clone());
+    }
+
+    fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+        values.record(&mut self.clone());
+    }
+
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        event.record(&mut self.clone());
+    }
+}
+
+#[test]
+fn should_trace_requests() {
+    let url = format!("{NIRVANA_URL}&inc=aliases");
+    let transport = FakeTransport::default();
+    transport
+        .respond(&url, 503, "")
+        .respond(&url, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_blocking_transport(transport);
+    client.retry_policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    };
+
+    let layer = FieldsLayer::default();
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .with_aliases()
+            .execute_with_client_blocking(&client)
+    })
+    .unwrap();
+
+    let fields = layer.fields.lock().unwrap();
+    for expected in [
+        "entity=artist",
+        "mbid=\"5b11f4ce-a62d-471e-81fc-a69a8278c7da\"",
+        "includes=aliases",
+        "status=503",
+        "retries=1",
+    ] {
+        assert!(
+            fields.contains(&expected.to_string()),
+            "{expected} not in {fields:?}"
+        );
+    }
+    assert!(fields
+        .iter()
+        .any(|field| field.starts_with("ratelimit_wait_ms=")));
+}
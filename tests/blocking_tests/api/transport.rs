@@ -0,0 +1,32 @@
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::entity::artist::*;
+use musicbrainz_rs::prelude::*;
+use reqwest::header::USER_AGENT;
+
+use crate::fake_transport::*;
+
+#[test]
+fn should_fetch_with_custom_transport() {
+    let transport = FakeTransport::default();
+    transport.respond(NIRVANA_URL, 200, NIRVANA_JSON);
+
+    let mut client = MusicBrainzClient::default();
+    client.set_user_agent("musicbrainz_rs_testing/0.9").unwrap();
+    client.set_blocking_transport(transport.clone());
+
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute_with_client_blocking(&client)
+        .unwrap();
+
+    assert_eq!(nirvana.name, "Nirvana");
+    assert_eq!(nirvana.artist_type, Some(ArtistType::Group));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, NIRVANA_URL);
+    assert_eq!(
+        requests[0].headers.get(USER_AGENT).unwrap(),
+        "musicbrainz_rs_testing/0.9"
+    );
+}
@@ -6,7 +6,7 @@ use musicbrainz_rs::prelude::*;
 fn browse_area_by_collection() {
     let areas = Area::browse()
         .by_collection("6b6dc74a-f779-491a-a0eb-7c1d1ed56fe0")
-        .execute();
+        .execute_blocking();
 
     assert!(areas.is_ok());
 }
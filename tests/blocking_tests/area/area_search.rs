@@ -10,7 +10,7 @@ fn should_search_area() {
         .tag("place")
         .build();
 
-    let result = Area::search(query).execute().unwrap();
+    let result = Area::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
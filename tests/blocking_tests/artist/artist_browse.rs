@@ -5,7 +5,7 @@ use musicbrainz_rs::prelude::*;
 fn should_browse_artist_by_release_groups() {
     let artistss_on_in_rainbows_rg = Artist::browse()
         .by_release_group("6e335887-60ba-38f0-95af-fae7774336bf")
-        .execute();
+        .execute_blocking();
 
     assert!(artistss_on_in_rainbows_rg.is_ok());
 
@@ -20,7 +20,7 @@ fn should_browse_artist_by_release_groups() {
 fn should_browse_artist_by_release() {
     let artists_on_in_utero_release = Artist::browse()
         .by_release("18d4e9b4-9247-4b44-914a-8ddec3502103")
-        .execute();
+        .execute_blocking();
 
     assert!(artists_on_in_utero_release.is_ok());
 
@@ -35,7 +35,7 @@ fn should_browse_artist_by_release() {
 fn should_browse_artist_by_area() {
     let artistss_in_aberdeen_area = Artist::browse()
         .by_area("a640b45c-c173-49b1-8030-973603e895b5")
-        .execute();
+        .execute_blocking();
 
     assert!(artistss_in_aberdeen_area.is_ok());
 
@@ -50,7 +50,7 @@ fn should_browse_artist_by_area() {
 fn should_browse_artist_by_work() {
     let artists_on_hotel_california = Artist::browse()
         .by_work("22457dc0-ecbf-38f5-9056-11c858530a50")
-        .execute();
+        .execute_blocking();
 
     let artists_on_hotel_california = artists_on_hotel_california.unwrap();
 
@@ -63,7 +63,7 @@ fn should_browse_artist_by_work() {
 fn should_browse_artist_by_recording() {
     let artists_on_polly = Artist::browse()
         .by_recording("af40d6b8-58e8-4ca5-9db8-d4fca0b899e2")
-        .execute();
+        .execute_blocking();
 
     let artists_on_polly = artists_on_polly.unwrap();
 
@@ -6,7 +6,7 @@ fn should_get_artist_releases() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_releases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let releases = john_lee_hooker.releases.unwrap();
@@ -21,7 +21,7 @@ fn should_get_artist_works() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_works()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let works = john_lee_hooker.works.unwrap();
@@ -36,7 +36,7 @@ fn should_get_artist_release_groups() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_release_groups()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let release_groups = john_lee_hooker.release_groups.unwrap();
@@ -51,7 +51,7 @@ fn should_get_artist_recordings() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_recordings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let recordings = john_lee_hooker.recordings.unwrap();
@@ -66,7 +66,7 @@ fn should_get_artist_aliases() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let aliases = john_lee_hooker.aliases;
@@ -82,7 +82,7 @@ fn should_get_artist_artist_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -95,7 +95,7 @@ fn should_get_artist_event_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_event_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -110,7 +110,7 @@ fn should_get_artist_url_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -125,7 +125,7 @@ fn should_get_artist_work_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_work_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -138,7 +138,7 @@ fn should_get_artist_recording_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_recording_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -151,7 +151,7 @@ fn should_get_artist_release_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_release_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -164,7 +164,7 @@ fn should_get_artist_series_relations() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_series_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = john_lee_hooker.relations.unwrap();
@@ -177,7 +177,7 @@ fn should_get_artist_artist_releases_with_disc_ids() {
     let nirvana = Artist::fetch()
         .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
         .with_releases_and_discids()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let releases_with_disc_ids = nirvana.releases.unwrap();
@@ -192,7 +192,7 @@ fn should_get_artist_tags() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(john_lee_hooker
@@ -207,7 +207,7 @@ fn should_get_artist_rating() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_rating()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(john_lee_hooker.rating.is_some());
@@ -218,7 +218,7 @@ fn should_get_artist_genres() {
     let john_lee_hooker = Artist::fetch()
         .id("b0122194-c49a-46a1-ade7-84d1d76bd8e9")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(john_lee_hooker
@@ -233,7 +233,7 @@ fn should_get_artist_annotation() {
     let franz_joseph_haydn = Artist::fetch()
         .id("c130b0fb-5dce-449d-9f40-1437f889f7fe")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(franz_joseph_haydn.annotation.is_some());
@@ -9,7 +9,7 @@ fn should_search_artist() {
         .artist_type("Group")
         .build();
 
-    let result = Artist::search(query).execute().unwrap();
+    let result = Artist::search(query).execute_blocking().unwrap();
 
     assert!(!result.entities.is_empty());
 }
@@ -5,7 +5,7 @@ use musicbrainz_rs::Search;
 fn should_search_cdstub() {
     let query = CDStubSearchQuery::query_builder().title("Dookie").build();
 
-    let result = CDStub::search(query).execute().unwrap();
+    let result = CDStub::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
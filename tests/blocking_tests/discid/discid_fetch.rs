@@ -14,7 +14,7 @@ fn fetch_cd_id() {
 
     for discid in discids {
         assert!(
-            Discid::fetch().id(discid).execute().is_ok(),
+            Discid::fetch().id(discid).execute_blocking().is_ok(),
             "{} is err",
             discid
         );
@@ -23,7 +23,7 @@ fn fetch_cd_id() {
     assert!(
         Discid::fetch()
             .id("hUbE6HKkLSkkWDvf4WliXO9OLm4-")
-            .execute()
+            .execute_blocking()
             .is_err(),
         "The diskid does not exist"
     );
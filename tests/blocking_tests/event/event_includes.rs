@@ -6,7 +6,7 @@ fn should_get_event_tags() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(dour_festival_1989.tags.is_some());
@@ -17,7 +17,7 @@ fn should_get_event_aliases() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(dour_festival_1989.aliases.is_some());
@@ -28,7 +28,7 @@ fn should_get_event_rating() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_ratings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(dour_festival_1989.rating.is_some());
@@ -39,7 +39,7 @@ fn should_get_event_genres() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(dour_festival_1989.genres.is_some());
@@ -50,7 +50,7 @@ fn should_get_event_annotation() {
     let kiss_at_huntington_center = Event::fetch()
         .id("24610e7f-eaa3-4c45-9f06-7f441b1a5dd7")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(kiss_at_huntington_center.annotation.is_some());
@@ -61,7 +61,7 @@ fn should_get_event_artist_relations() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = dour_festival_1989.relations.unwrap();
@@ -76,7 +76,7 @@ fn should_get_event_place_relations() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_place_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = dour_festival_1989.relations.unwrap();
@@ -89,7 +89,7 @@ fn should_get_event_series_relations() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_series_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = dour_festival_1989.relations.unwrap();
@@ -102,7 +102,7 @@ fn should_get_event_url_relations() {
     let dour_festival_1989 = Event::fetch()
         .id("73df2f48-383b-4930-bad3-05ba938be578")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = dour_festival_1989.relations.unwrap();
@@ -9,7 +9,7 @@ fn should_search_event() {
         .arid("e935d070-004d-405a-8b9d-1d9e51590b55")
         .build();
 
-    let result = Event::search(query).execute().unwrap();
+    let result = Event::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
@@ -6,7 +6,7 @@ fn should_get_instrument_tags() {
     let guitar = Instrument::fetch()
         .id("63021302-86cd-4aee-80df-2270d54f4978")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(guitar.tags.unwrap().iter().any(|tag| tag.name == "wood"));
@@ -17,7 +17,7 @@ fn should_get_instrument_aliases() {
     let guitar = Instrument::fetch()
         .id("63021302-86cd-4aee-80df-2270d54f4978")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(guitar
@@ -32,7 +32,7 @@ fn should_get_instrument_genres() {
     let guitar = Instrument::fetch()
         .id("63021302-86cd-4aee-80df-2270d54f4978")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(guitar.genres.is_some());
@@ -43,7 +43,7 @@ fn should_get_instrument_annotation() {
     let gusli = Instrument::fetch()
         .id("bb08cebd-ff6c-49e8-8f8f-914cc2d68c27")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(gusli.annotation.is_some());
@@ -54,7 +54,7 @@ fn should_get_instrument_url_relations() {
     let guitar = Instrument::fetch()
         .id("63021302-86cd-4aee-80df-2270d54f4978")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = guitar.relations.unwrap();
@@ -8,7 +8,7 @@ fn should_search_instrument() {
         .instrument("octobass")
         .build();
 
-    let result = Instrument::search(query).execute().unwrap();
+    let result = Instrument::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
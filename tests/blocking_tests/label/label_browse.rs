@@ -5,7 +5,7 @@ use musicbrainz_rs::prelude::*;
 fn should_browse_label_by_area() {
     let labels_in_paris = Label::browse()
         .by_area("dc10c22b-e510-4006-8b7f-fecb4f36436e")
-        .execute();
+        .execute_blocking();
 
     assert!(labels_in_paris.is_ok());
 
@@ -20,7 +20,7 @@ fn should_browse_label_by_area() {
 fn should_browse_label_by_release() {
     let label_of_justice_cross_release = Label::browse()
         .by_release("4642ee19-7790-3c8d-ab5e-d133de942db6")
-        .execute();
+        .execute_blocking();
 
     assert!(label_of_justice_cross_release.is_ok());
 
@@ -36,7 +36,7 @@ fn should_browse_label_by_release() {
 fn should_browse_label_by_collection() {
     let label_on_collection = Label::browse()
         .by_collection("91565a03-bce8-47e9-ab70-e4d4e1684d7f")
-        .execute();
+        .execute_blocking();
 
     assert!(label_on_collection.is_ok());
 
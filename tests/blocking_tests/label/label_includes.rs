@@ -6,7 +6,7 @@ fn should_get_label_releases() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_releases()
-        .execute();
+        .execute_blocking();
 
     let releases = ninja_tune.unwrap().releases;
 
@@ -21,7 +21,7 @@ fn should_get_label_aliases() {
     let motown = Label::fetch()
         .id("8e479e57-ef44-490c-b75d-cd28df89bf1b")
         .with_aliases()
-        .execute();
+        .execute_blocking();
 
     let aliases = motown.unwrap().aliases;
 
@@ -36,7 +36,7 @@ fn should_get_label_tags() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ninja_tune
@@ -51,7 +51,7 @@ fn should_get_label_rating() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_ratings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ninja_tune.rating.is_some());
@@ -62,7 +62,7 @@ fn should_get_label_genres() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ninja_tune.genres.is_some());
@@ -73,7 +73,7 @@ fn should_get_label_annotation() {
     let tokuma_japan_communications = Label::fetch()
         .id("040439f9-578b-45b6-b07b-d6c97e544859")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(tokuma_japan_communications.annotation.is_some());
@@ -84,7 +84,7 @@ fn should_get_label_artist_relations() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = ninja_tune.relations.unwrap();
@@ -99,7 +99,7 @@ fn should_get_label_label_relations() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_label_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = ninja_tune.relations.unwrap();
@@ -114,7 +114,7 @@ fn should_get_label_recording_relations() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_recording_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = ninja_tune.relations.unwrap();
@@ -129,7 +129,7 @@ fn should_get_label_release_relations() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_release_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = ninja_tune.relations.unwrap();
@@ -142,7 +142,7 @@ fn should_get_label_url_relations() {
     let ninja_tune = Label::fetch()
         .id("dc940013-b8a8-4362-a465-291026c04b42")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = ninja_tune.relations.unwrap();
@@ -7,7 +7,7 @@ fn should_search_label() {
         .label("Abbey Road Studios")
         .build();
 
-    let result = Label::search(query).execute().unwrap();
+    let result = Label::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
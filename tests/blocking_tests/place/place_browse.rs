@@ -5,7 +5,7 @@ use musicbrainz_rs::prelude::*;
 fn should_browse_place_by_area() {
     let places_in_paris = Place::browse()
         .by_area("dc10c22b-e510-4006-8b7f-fecb4f36436e")
-        .execute();
+        .execute_blocking();
 
     assert!(places_in_paris.is_ok());
 
@@ -21,7 +21,7 @@ fn should_browse_place_by_area() {
 fn should_browse_place_by_collection() {
     let places_in_collection = Place::browse()
         .by_collection("91565a03-bce8-47e9-ab70-e4d4e1684d7f")
-        .execute();
+        .execute_blocking();
 
     assert!(places_in_collection.is_ok());
 }
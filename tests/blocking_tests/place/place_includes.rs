@@ -6,7 +6,7 @@ fn should_get_place_aliases() {
     let blue_note = Place::fetch()
         .id("327c29c6-da63-4dc9-a117-1917ee691ce4")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(blue_note.aliases.is_some());
@@ -17,7 +17,7 @@ fn should_get_place_tags() {
     let olympia = Place::fetch()
         .id("36678fc4-2fee-46be-b084-4c4e2314ce71")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(olympia.tags.is_some());
@@ -27,7 +27,7 @@ fn should_get_place_genres() {
     let olympia = Place::fetch()
         .id("36678fc4-2fee-46be-b084-4c4e2314ce71")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(olympia.genres.is_some());
@@ -38,7 +38,7 @@ fn should_get_place_annotation() {
     let osaka_kosei_nenkin_kaikan = Place::fetch()
         .id("751f998a-60ca-4d48-954f-b101d59ad89a")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(osaka_kosei_nenkin_kaikan.annotation.is_some());
@@ -49,7 +49,7 @@ fn should_get_place_event_relations() {
     let olympia = Place::fetch()
         .id("36678fc4-2fee-46be-b084-4c4e2314ce71")
         .with_event_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = olympia.relations.unwrap();
@@ -62,7 +62,7 @@ fn should_get_place_recording_relations() {
     let olympia = Place::fetch()
         .id("36678fc4-2fee-46be-b084-4c4e2314ce71")
         .with_recording_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = olympia.relations.unwrap();
@@ -80,7 +80,7 @@ fn should_get_place_recording_relations() {
 //     let olympia = Place::fetch()
 //         .id("36678fc4-2fee-46be-b084-4c4e2314ce71")
 //         .with_release_relations()
-//         .execute()
+//         .execute_blocking()
 //         .unwrap();
 
 //     let relations = olympia.relations.unwrap();
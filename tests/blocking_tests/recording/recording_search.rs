@@ -11,7 +11,7 @@ fn should_search_recording() {
         .comment("leeds festival")
         .build();
 
-    let result = Recording::search(query).execute().unwrap();
+    let result = Recording::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
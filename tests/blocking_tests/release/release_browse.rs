@@ -5,7 +5,7 @@ use musicbrainz_rs::prelude::*;
 fn should_browse_release_by_artist() {
     let releases_by_svinkels = Release::browse()
         .by_artist("770d490e-c89b-4775-8508-aca7c75142cd")
-        .execute();
+        .execute_blocking();
 
     assert!(releases_by_svinkels.is_ok());
 
@@ -20,7 +20,7 @@ fn should_browse_release_by_artist() {
 fn should_browse_release_by_area() {
     let releases_france = Release::browse()
         .by_area("08310658-51eb-3801-80de-5a0739207115")
-        .execute();
+        .execute_blocking();
 
     assert!(releases_france.is_ok());
 
@@ -35,7 +35,7 @@ fn should_browse_release_by_area() {
 fn should_browse_release_by_label() {
     let ninjatune_releases = Release::browse()
         .by_label("dc940013-b8a8-4362-a465-291026c04b42")
-        .execute();
+        .execute_blocking();
 
     assert!(ninjatune_releases.is_ok());
 
@@ -50,7 +50,7 @@ fn should_browse_release_by_label() {
 fn should_browse_release_by_recording() {
     let release_of_l_ecole_du_micro_d_argent = Release::browse()
         .by_recording("72984ccf-9edb-44d4-aad2-f41c9ef5414a")
-        .execute();
+        .execute_blocking();
 
     assert!(release_of_l_ecole_du_micro_d_argent.is_ok());
 
@@ -65,7 +65,7 @@ fn should_browse_release_by_recording() {
 fn should_browse_release_by_track() {
     let release_with_phantom_by_justice = Release::browse()
         .by_track("d5bbc037-eace-3712-9af8-ecb378b65dd6")
-        .execute();
+        .execute_blocking();
 
     assert!(release_with_phantom_by_justice.is_ok());
 
@@ -80,7 +80,7 @@ fn should_browse_release_by_track() {
 fn should_browse_release_by_track_artist() {
     let release_featuring_akhenaton = Release::browse()
         .by_track_artist("55808e24-5150-4abd-b86b-7c3d7a080da3")
-        .execute();
+        .execute_blocking();
 
     assert!(release_featuring_akhenaton.is_ok());
 
@@ -96,7 +96,7 @@ fn should_browse_release_by_track_artist_with_recordings() {
     let release_featuring_akhenaton = Release::browse()
         .by_track_artist("55808e24-5150-4abd-b86b-7c3d7a080da3")
         .with_recordings()
-        .execute();
+        .execute_blocking();
 
     let release_featuring_akhenaton = release_featuring_akhenaton.unwrap();
 
@@ -109,7 +109,7 @@ fn should_browse_release_by_track_artist_with_recordings() {
 fn should_browse_release_by_release_group() {
     let neil_young_harvest_releases = Release::browse()
         .by_release_group("b25419cf-71bf-3a54-8cd4-2161c61056a0")
-        .execute();
+        .execute_blocking();
 
     assert!(neil_young_harvest_releases.is_ok());
 
@@ -124,7 +124,7 @@ fn should_browse_release_by_release_group() {
 fn should_browse_release_by_collection() {
     let releases_in_collection = Release::browse()
         .by_collection("91565a03-bce8-47e9-ab70-e4d4e1684d7f")
-        .execute();
+        .execute_blocking();
 
     assert!(releases_in_collection.is_ok());
 
@@ -7,7 +7,7 @@ use musicbrainz_rs::FetchCoverart;
 fn should_get_release_coverart() {
     let in_utero_coverart = Release::fetch_coverart()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get cover art");
 
     if let CoverartResponse::Json(coverart) = in_utero_coverart {
@@ -22,12 +22,12 @@ fn should_get_release_coverart() {
 fn should_get_release_coverart_after_fetch() {
     let in_utero = Release::fetch()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get release");
 
     let in_utero_coverart = in_utero
         .get_coverart()
-        .execute()
+        .execute_blocking()
         .expect("Unable to get coverart");
 
     if let CoverartResponse::Json(coverart) = in_utero_coverart {
@@ -9,7 +9,7 @@ fn should_get_release_release_groups() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_release_groups()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert_eq!(justice_cross.release_group.unwrap().title, "✝");
@@ -20,7 +20,7 @@ fn should_get_release_media() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_media()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(justice_cross
@@ -35,7 +35,7 @@ fn should_get_release_discids() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_discids()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let medias: Vec<Media> = justice_cross.media.unwrap();
@@ -54,7 +54,7 @@ fn should_get_release_recordings() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_recordings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let medias: Vec<Media> = justice_cross.media.unwrap();
@@ -73,7 +73,7 @@ fn should_get_release_artists() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_artists()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert_eq!(justice_cross.artist_credit.unwrap()[0].name, "Justice");
@@ -84,7 +84,7 @@ fn should_get_release_label() {
     let justice_cross = Release::fetch()
         .id("4642ee19-7790-3c8d-ab5e-d133de942db6")
         .with_labels()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(justice_cross
@@ -99,7 +99,7 @@ fn should_get_release_tags() {
     let l_ecole_du_micro_d_argent = Release::fetch()
         .id("cba0035e-d8c9-4390-8569-02bdadaf87d3")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(l_ecole_du_micro_d_argent
@@ -114,7 +114,7 @@ fn should_get_release_artist_relations() {
     let in_utero = Release::fetch()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = in_utero.relations.unwrap();
@@ -127,7 +127,7 @@ fn should_get_release_url_relations() {
     let in_utero = Release::fetch()
         .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = in_utero.relations.unwrap();
@@ -144,7 +144,7 @@ fn should_get_release_level_relations() {
         .with_work_level_relations()
         .with_artist_relations()
         .with_recording_level_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let mut target_types = HashSet::new();
@@ -177,7 +177,7 @@ fn should_get_release_aliases() {
     let l_ecole_du_micro_d_argent = Release::fetch()
         .id("cba0035e-d8c9-4390-8569-02bdadaf87d3")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(l_ecole_du_micro_d_argent.aliases.is_some());
@@ -188,7 +188,7 @@ fn should_get_release_genres() {
     let l_ecole_du_micro_d_argent = Release::fetch()
         .id("cba0035e-d8c9-4390-8569-02bdadaf87d3")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(l_ecole_du_micro_d_argent.genres.is_some());
@@ -199,7 +199,7 @@ fn should_get_release_annotation() {
     let pieds_nus_sur_la_braise = Release::fetch()
         .id("bdb24cb5-404b-4f60-bba4-7b730325ae47")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(pieds_nus_sur_la_braise.annotation.is_some());
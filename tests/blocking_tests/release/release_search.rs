@@ -7,7 +7,7 @@ fn should_search_artist() {
         .release("Drivers License")
         .build();
 
-    let result = Release::search(query).execute().unwrap();
+    let result = Release::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
@@ -7,7 +7,7 @@ use musicbrainz_rs::FetchCoverart;
 fn should_get_release_group_coverart() {
     let echoes_coverart = ReleaseGroup::fetch_coverart()
         .id("ccdb3c9b-67e8-46f5-803f-026ef815ceea")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get cover art");
 
     if let CoverartResponse::Json(coverart) = echoes_coverart {
@@ -22,12 +22,12 @@ fn should_get_release_group_coverart() {
 fn should_get_release_group_coverart_after_fetch() {
     let echoes = ReleaseGroup::fetch()
         .id("ccdb3c9b-67e8-46f5-803f-026ef815ceea")
-        .execute()
+        .execute_blocking()
         .expect("Unable to get release");
 
     let echoes_coverart = echoes
         .get_coverart()
-        .execute()
+        .execute_blocking()
         .expect("Unable to get coverart");
 
     if let CoverartResponse::Json(coverart) = echoes_coverart {
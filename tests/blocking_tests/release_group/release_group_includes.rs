@@ -6,7 +6,7 @@ fn should_get_release_group_artists() {
     let harvest = ReleaseGroup::fetch()
         .id("b25419cf-71bf-3a54-8cd4-2161c61056a0")
         .with_artists()
-        .execute();
+        .execute_blocking();
 
     assert!(harvest
         .unwrap()
@@ -21,7 +21,7 @@ fn should_get_release_group_releases() {
     let harvest = ReleaseGroup::fetch()
         .id("b25419cf-71bf-3a54-8cd4-2161c61056a0")
         .with_releases()
-        .execute();
+        .execute_blocking();
 
     assert!(harvest
         .unwrap()
@@ -36,7 +36,7 @@ fn should_get_release_group_tags() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(in_utero
@@ -51,7 +51,7 @@ fn should_get_release_group_aliases() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(in_utero.aliases.is_some());
@@ -62,7 +62,7 @@ fn should_get_release_group_rating() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_ratings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(in_utero.rating.is_some());
@@ -73,7 +73,7 @@ fn should_get_release_group_genres() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(in_utero.genres.is_some());
@@ -84,7 +84,7 @@ fn should_get_release_group_annotation() {
     let dirt = ReleaseGroup::fetch()
         .id("92d8f0c4-8c64-3bee-bee1-812a70e77efa")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(dirt.annotation.is_some());
@@ -98,7 +98,7 @@ fn should_get_release_group_annotation() {
 //     let in_utero = ReleaseGroup::fetch()
 //         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
 //         .with_release_group_relations()
-//         .execute()
+//         .execute_blocking()
 //         .unwrap();
 
 //     let relations = in_utero.relations.unwrap();
@@ -111,7 +111,7 @@ fn should_get_release_group_series_relations() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_series_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = in_utero.relations.unwrap();
@@ -124,7 +124,7 @@ fn should_get_release_group_url_relations() {
     let in_utero = ReleaseGroup::fetch()
         .id("2a0981fb-9593-3019-864b-ce934d97a16e")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = in_utero.relations.unwrap();
@@ -7,7 +7,7 @@ fn should_search_artist() {
         .release("Tonight")
         .build();
 
-    let result = ReleaseGroup::search(query).execute().unwrap();
+    let result = ReleaseGroup::search(query).execute_blocking().unwrap();
 
     assert!(!result.entities.is_empty());
 }
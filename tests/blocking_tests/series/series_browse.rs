@@ -6,7 +6,7 @@ use musicbrainz_rs::prelude::*;
 fn browse_series_by_collection() {
     let series_in_collection = Series::browse()
         .by_collection("91565a03-bce8-47e9-ab70-e4d4e1684d7f")
-        .execute();
+        .execute_blocking();
 
     assert!(series_in_collection.is_ok());
 }
@@ -6,7 +6,7 @@ fn should_get_series_tags() {
     let breaks_loop_n_edits = Series::fetch()
         .id("0c66e70d-5f23-4579-8fe5-6bc0007428a2")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(breaks_loop_n_edits
@@ -21,7 +21,7 @@ fn should_get_series_aliases() {
     let ultimate_breaks_and_beats = Series::fetch()
         .id("3e5979c8-5a78-4d0b-878a-0fb87853effe")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ultimate_breaks_and_beats.aliases.is_some());
@@ -32,7 +32,7 @@ fn should_get_series_genres() {
     let ultimate_breaks_and_beats = Series::fetch()
         .id("3e5979c8-5a78-4d0b-878a-0fb87853effe")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(ultimate_breaks_and_beats.genres.is_some());
@@ -43,7 +43,7 @@ fn should_get_series_annotation() {
     let record_store_day_2020 = Series::fetch()
         .id("c1071cec-48f1-4231-ac8e-8c64e15ec7cd")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(record_store_day_2020.annotation.is_some());
@@ -57,7 +57,7 @@ fn should_get_series_annotation() {
 //     let ultimate_breaks_and_beats = Series::fetch()
 //         .id("3e5979c8-5a78-4d0b-878a-0fb87853effe")
 //         .with_release_group_relations()
-//         .execute()
+//         .execute_blocking()
 //         .unwrap();
 
 //     let relations = ultimate_breaks_and_beats.relations.unwrap();
@@ -9,7 +9,7 @@ fn should_search_series() {
         .comment("denmark")
         .build();
 
-    let result = Series::search(query).execute().unwrap();
+    let result = Series::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
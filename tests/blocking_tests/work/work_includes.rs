@@ -6,7 +6,7 @@ fn should_get_work_tags() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_tags()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(hotel_california
@@ -21,7 +21,7 @@ fn should_get_work_aliases() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_aliases()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(hotel_california.aliases.is_some());
@@ -32,7 +32,7 @@ fn should_get_work_rating() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_ratings()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(hotel_california.rating.is_some());
@@ -43,7 +43,7 @@ fn should_get_work_genres() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_genres()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(hotel_california.genres.is_some());
@@ -54,7 +54,7 @@ fn should_get_work_annotation() {
     let vater_unser_im_himmelreich = Work::fetch()
         .id("85ab2b66-cf0b-47e9-beee-34c64a5ddea1")
         .with_annotations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     assert!(vater_unser_im_himmelreich.annotation.is_some());
@@ -65,7 +65,7 @@ fn should_get_work_artist_relations() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_artist_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = hotel_california.relations.unwrap();
@@ -78,7 +78,7 @@ fn should_get_work_label_relations() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_label_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = hotel_california.relations.unwrap();
@@ -93,7 +93,7 @@ fn should_get_work_recording_relations() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_recording_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = hotel_california.relations.unwrap();
@@ -108,7 +108,7 @@ fn should_get_work_url_relations() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_url_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = hotel_california.relations.unwrap();
@@ -121,7 +121,7 @@ fn should_get_work_work_relations() {
     let hotel_california = Work::fetch()
         .id("22457dc0-ecbf-38f5-9056-11c858530a50")
         .with_work_relations()
-        .execute()
+        .execute_blocking()
         .unwrap();
 
     let relations = hotel_california.relations.unwrap();
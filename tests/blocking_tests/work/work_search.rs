@@ -9,7 +9,7 @@ fn should_search_work() {
         .arid("ea547ae6-8ab1-48b2-b1a9-70a638d2ad26")
         .build();
 
-    let result = Work::search(query).execute().unwrap();
+    let result = Work::search(query).execute_blocking().unwrap();
 
     assert!(result
         .entities
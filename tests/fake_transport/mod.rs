@@ -0,0 +1,153 @@
+//! An in-memory transport, to test the client without hitting the MusicBrainz API
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use musicbrainz_rs::client::MusicBrainzClient;
+use musicbrainz_rs::transport::*;
+use musicbrainz_rs::Error;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+#[derive(Debug, Default, Clone)]
+pub struct FakeTransport {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// The responses to give for each url. The last one is repeated once the others are consumed
+    responses: HashMap<String, VecDeque<HttpResponse>>,
+
+    /// All the requests received by the transport
+    requests: Vec<HttpRequest>,
+}
+
+impl FakeTransport {
+    /// Queue a response for the given url
+    pub fn respond(&self, url: &str, status: u16, body: &str) -> &Self {
+        self.respond_with_headers(url, status, HeaderMap::new(), body)
+    }
+
+    /// Queue a response with headers for the given url
+    pub fn respond_with_headers(
+        &self,
+        url: &str,
+        status: u16,
+        headers: HeaderMap,
+        body: &str,
+    ) -> &Self {
+        let response = HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            url: url.to_string(),
+            headers,
+            body: body.as_bytes().to_vec(),
+        };
+
+        self.inner
+            .lock()
+            .unwrap()
+            .responses
+            .entry(url.to_string())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// The requests received so far
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+
+    fn handle(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mut inner = self.inner.lock().unwrap();
+        let url = request.url.clone();
+        inner.requests.push(request);
+
+        let Some(queue) = inner.responses.get_mut(&url) else {
+            return Ok(HttpResponse {
+                status: StatusCode::NOT_FOUND,
+                url,
+                headers: HeaderMap::new(),
+                body: br#"{"error": "Not Found", "help": "For usage, please see: https://musicbrainz.org/development/mmd"}"#.to_vec(),
+            });
+        };
+
+        if queue.len() > 1 {
+            Ok(queue.pop_front().unwrap())
+        } else {
+            Ok(queue.front().unwrap().clone())
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Transport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move { self.handle(request) })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        self.handle(request)
+    }
+}
+
+/// A default client sending its requests through the transport
+pub fn client_with(transport: &FakeTransport) -> MusicBrainzClient {
+    let mut client = MusicBrainzClient::default();
+    #[cfg(feature = "async")]
+    client.set_transport(transport.clone());
+    #[cfg(feature = "blocking")]
+    client.set_blocking_transport(transport.clone());
+    client
+}
+
+/// The MBID of the `i`-th generated artist
+pub fn artist_id(i: usize) -> String {
+    format!("00000000-0000-0000-0000-{i:012}")
+}
+
+/// The JSON list of the generated artists in `range`
+fn artists_json(range: Range<usize>) -> String {
+    let artists: Vec<String> = range
+        .map(|i| format!(r#"{{"id": "{}", "name": "Artist {i}"}}"#, artist_id(i)))
+        .collect();
+
+    format!("[{}]", artists.join(","))
+}
+
+/// A browse response with the generated artists in `range`, out of `count` artists
+pub fn artist_browse_page(range: Range<usize>, offset: usize, count: usize) -> String {
+    format!(
+        r#"{{"artist-count": {count}, "artist-offset": {offset}, "artists": {}}}"#,
+        artists_json(range)
+    )
+}
+
+/// A search response with the generated artists in `range`, out of `count` artists
+pub fn artist_search_page(range: Range<usize>, offset: usize, count: usize) -> String {
+    format!(
+        r#"{{"created": "2024-01-01T00:00:00.000Z", "count": {count}, "offset": {offset}, "artists": {}}}"#,
+        artists_json(range)
+    )
+}
+
+/// A minimal artist lookup response
+pub const NIRVANA_JSON: &str = r#"{
+    "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+    "name": "Nirvana",
+    "sort-name": "Nirvana",
+    "disambiguation": "1980s–1990s US grunge band",
+    "type": "Group",
+    "country": "US"
+}"#;
+
+pub const NIRVANA_URL: &str =
+    "http://musicbrainz.org/ws/2/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json";
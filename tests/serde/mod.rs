@@ -11,6 +11,31 @@ mod search {
     include!(concat!(env!("OUT_DIR"), "/search.rs"));
 }
 
+/// Check that every key of the `original` JSON is still in the `serialized` one
+#[cfg(all(feature = "extra_fields", not(feature = "legacy_serialize")))]
+pub fn assert_keys_kept(original: &serde_json::Value, serialized: &serde_json::Value, path: &str) {
+    use serde_json::Value;
+
+    match (original, serialized) {
+        (Value::Object(original), Value::Object(serialized)) => {
+            for (key, value) in original {
+                let path = format!("{path}.{key}");
+                let Some(serialized_value) = serialized.get(key) else {
+                    panic!("`{path}` was lost in the roundtrip");
+                };
+                assert_keys_kept(value, serialized_value, &path);
+            }
+        }
+        (Value::Array(original), Value::Array(serialized)) => {
+            assert_eq!(original.len(), serialized.len(), "`{path}` changed length");
+            for (i, (value, serialized_value)) in original.iter().zip(serialized).enumerate() {
+                assert_keys_kept(value, serialized_value, &format!("{path}[{i}]"));
+            }
+        }
+        _ => {}
+    }
+}
+
 mod entity {
     #[test]
     fn test_recording_fields() {
@@ -49,6 +74,8 @@ mod entity {
             Some(ReleaseTextRepresentation {
                 script: Some(ReleaseScript::Latn),
                 language: Some(Language::Eng),
+                #[cfg(feature = "extra_fields")]
+                extra: Default::default(),
             })
         );
         // TODO: Add support for collections field.
@@ -64,14 +91,14 @@ mod entity {
         // See <https://github.com/RustyNova016/musicbrainz_rs/issues/46>.
         assert_eq!(
             release.status_id,
-            Some("4e304316-386d-3409-af2e-78857eec5cfe".to_string())
+            Some("4e304316-386d-3409-af2e-78857eec5cfe".parse().unwrap())
         );
         assert!(release.genres.is_some_and(|x| x.is_empty()));
         assert_eq!(release.status, Some(ReleaseStatus::Official));
         assert!(release.relations.is_some_and(|x| x.len() == 1));
         assert_eq!(
             release.packaging_id,
-            Some("ec27701a-4a22-37f4-bfac-6616e0f9750a".to_string())
+            Some("ec27701a-4a22-37f4-bfac-6616e0f9750a".parse().unwrap())
         );
         assert!(release.media.is_some_and(|x| x.len() == 1));
         assert_eq!(release.quality, Some(ReleaseQuality::Normal));
@@ -81,4 +108,57 @@ mod entity {
         assert_eq!(release.title, "A Hot Summer Night in ’59".to_string());
         assert_eq!(release.annotation, None);
     }
+
+    #[cfg(feature = "extra_fields")]
+    #[test]
+    fn test_release_extra_fields() {
+        use musicbrainz_rs::entity::release::*;
+        let release: Release = serde_json::from_str(include_str!(
+            "data/lookup/release//b1dc9838-adf3-43f2-93f9-802b46e5fe59.json"
+        ))
+        .unwrap();
+        assert!(release.extra.contains_key("collections"));
+        assert_eq!(release.extra["cover-art-archive"]["artwork"], false);
+        assert!(!release.extra.contains_key("title"));
+
+        let serialized = serde_json::to_value(&release).unwrap();
+        assert_eq!(
+            serialized["collections"],
+            release.extra["collections"].clone()
+        );
+    }
+
+    #[test]
+    fn test_search_scores() {
+        use musicbrainz_rs::entity::search::SearchResult;
+        use musicbrainz_rs::entity::series::Series;
+        let result: SearchResult<Series> =
+            serde_json::from_str(include_str!("data/search/series/Studio_Brussel.json")).unwrap();
+        assert_eq!(result.scores, vec![Some(100), Some(90), Some(42)]);
+        let (series, score) = result.hits().last().unwrap();
+        assert_eq!(series.name, "De Nieuwe Lichting".to_string());
+        assert_eq!(score, Some(42));
+    }
+
+    #[cfg(not(feature = "legacy_serialize"))]
+    #[test]
+    fn test_search_scores_serialized_once() {
+        use musicbrainz_rs::entity::search::SearchResult;
+        use musicbrainz_rs::entity::series::Series;
+        use musicbrainz_rs::entity::tag::Tag;
+        let series: SearchResult<Series> =
+            serde_json::from_str(include_str!("data/search/series/Studio_Brussel.json")).unwrap();
+        let tags: SearchResult<Tag> =
+            serde_json::from_str(include_str!("data/search/tag/shoegaze.json")).unwrap();
+
+        let serialized = serde_json::to_string(&series).unwrap();
+        assert_eq!(serialized.matches(r#""score":42"#).count(), 1);
+
+        // Tags keep their score, which would be a duplicate field if serialized again
+        let serialized = serde_json::to_string(&tags).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SearchResult<Tag>>(&serialized).unwrap(),
+            tags
+        );
+    }
 }
@@ -1,3 +1,4 @@
+mod fake_transport;
 mod serde;
 
 #[cfg(feature = "async")]